@@ -47,6 +47,7 @@ impl Display for Argument {
 
 impl Argument {
     pub fn desugar(&mut self) -> ZResult<&mut Self> {
+        self.ty.desugar()?;
         self.default = self.default.as_ref().map(AstData::desugared).transpose()?;
         Ok(self)
     }
@@ -54,7 +55,7 @@ impl Argument {
         let ty1 = Arc::clone(self.ty.type_check(ty_symt)?.as_const()?);
         if let Some(default) = &mut self.default {
             let ty2 = default.type_check(ty_symt)?;
-            if !Arc::ptr_eq(&ty1, &ty2) {
+            if !ty2.is_assignable_to(&ty1) {
                 return Err(ZError::t011(&ty1, &ty2).with_span(&*self));
             }
         }
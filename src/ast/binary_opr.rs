@@ -1,14 +1,18 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
+use smol_str::SmolStr;
 use tracing::debug;
 
 use crate::{
     ast::{Ast, AstData, Call, Member, Reconstruct},
-    errors::ZError,
-    primitives::{BOOL_T, BOOL_T_VAL, PROC_T, TYPE_T},
+    errors::{ToZResult, ZError},
+    primitives::{
+        generic_cpx, generic_frac, generic_proc, res_args, BOOL_T, BOOL_T_VAL, CPX_T, FRAC_T,
+        OPT_T_VAL, PROC_T, TYPE_T, TYPE_T_VAL,
+    },
     types::{
         position::{GetSpan, Span},
-        r#type::TypeCheckType,
+        r#type::{Type, TypeCheckType, ValueType},
         sym_table::TypeCheckSymTable,
         token::{AccessType, OprType},
     },
@@ -30,6 +34,31 @@ impl GetSpan for BinaryOpr {
     }
 }
 
+impl BinaryOpr {
+    /// For `x istype T` and `x isnttype T`, returns `x` and the types it is narrowed to when the
+    /// condition is true and when it is false
+    pub fn narrowing(
+        &mut self,
+        ty_symt: &mut TypeCheckSymTable,
+    ) -> ZResult<Option<(SmolStr, Arc<Type>, Arc<Type>)>> {
+        let (OprType::Istype | OprType::Isnttype, Ast::Ident(ident)) = (self.ty, &*self.operand1)
+        else {
+            return Ok(None);
+        };
+        let var_ty = ty_symt.get_val(&ident.name, ident)?;
+        if var_ty.as_const().is_ok() {
+            return Ok(None);
+        }
+        let ty = Arc::clone(self.operand2.type_check(ty_symt)?.as_const()?);
+        let (if_true, if_false) = (var_ty.narrow(&ty, true), var_ty.narrow(&ty, false));
+        Ok(Some(if self.ty == OprType::Istype {
+            (ident.name.to_owned(), if_true, if_false)
+        } else {
+            (ident.name.to_owned(), if_false, if_true)
+        }))
+    }
+}
+
 impl AstData for BinaryOpr {
     fn as_variant(&self) -> Ast {
         Ast::BinaryOpr(self.to_owned())
@@ -45,10 +74,44 @@ impl AstData for BinaryOpr {
                 let ty2 = ty2.as_const()?;
                 if Arc::ptr_eq(ty2, &TYPE_T) {
                     TypeCheckType::Const(Arc::clone(&ty1))
+                } else if Arc::ptr_eq(ty2, &CPX_T) && ty1.is_num() {
+                    // a number cast to `cpx` or `frac` becomes its real part or numerator
+                    generic_cpx(Arc::clone(&ty1)).into()
+                } else if Arc::ptr_eq(ty2, &FRAC_T) && ty1.is_int() {
+                    generic_frac(Arc::clone(&ty1)).into()
                 } else {
                     Arc::clone(ty2).into()
                 }
             }),
+            OprType::Istype | OprType::Isnttype => {
+                ty2.as_const().map_err(|e| e.with_span(&*self.operand2))?;
+                Ok(Arc::clone(&BOOL_T).into())
+            }
+            OprType::Is | OprType::Isnt | OprType::Iseq | OprType::Isnteq => {
+                Ok(Arc::clone(&BOOL_T).into())
+            }
+            OprType::Handle => {
+                let Some((ok_ty, err_ty)) = res_args(&ty1) else {
+                    return Err(ZError::t025(&ty1).with_span(&*self.operand1));
+                };
+                let Some((arg_tys, ret_ty)) = ty2.proc_sig() else {
+                    return Err(ZError::t011(
+                        &generic_proc(vec![err_ty], Arc::clone(&ok_ty)),
+                        &ty2,
+                    )
+                    .with_span(&*self.operand2));
+                };
+                if arg_tys.len() != 1 {
+                    return Err(ZError::t015(1, arg_tys.len()).with_span(&*self.operand2));
+                }
+                if !err_ty.is_assignable_to(&arg_tys[0]) {
+                    return Err(ZError::t004(&arg_tys[0], &err_ty).with_span(&*self.operand2));
+                }
+                if !ret_ty.is_assignable_to(&ok_ty) {
+                    return Err(ZError::t009(&ok_ty, &ret_ty).with_span(&*self.operand2));
+                }
+                Ok(ok_ty.into())
+            }
             _ => unreachable!(),
         }
     }
@@ -70,13 +133,30 @@ impl AstData for BinaryOpr {
                 }
                 new_self.as_variant()
             }
-            OprType::TypeCast => {
-                debug!(span = ?self.span(), "Desugaring @ operator");
+            OprType::TypeCast
+            | OprType::Handle
+            | OprType::Istype
+            | OprType::Isnttype
+            | OprType::Is
+            | OprType::Isnt
+            | OprType::Iseq
+            | OprType::Isnteq => {
+                debug!(span = ?self.span(), "Desugaring {} operator", self.ty);
                 let mut new_self = self.to_owned();
                 new_self.operand1.desugar()?;
                 new_self.operand2.desugar()?;
                 new_self.as_variant()
             }
+            OprType::Pipe => {
+                debug!(span = ?self.span(), "Desugaring |> operator");
+                Call {
+                    called: self.operand2.desugared()?.into(),
+                    paren_spans: None,
+                    args: vec![self.operand1.desugared()?],
+                    kwargs: vec![],
+                }
+                .desugared()?
+            }
             _ => {
                 debug!(span = ?self.span(), "Desugaring miscellaneous binary operator");
                 Call {
@@ -95,7 +175,18 @@ impl AstData for BinaryOpr {
                             OprType::Gt => "_gt",
                             OprType::Ge => "_ge",
                             OprType::Concat => "_concat",
-                            _ => unimplemented!("{:#?}", self.ty),
+                            OprType::Range => "_range",
+                            OprType::Coalesce => "_coalesce",
+                            OprType::Pow => "_pow",
+                            OprType::BitAnd => "and",
+                            OprType::BitOr => "or",
+                            OprType::BitXor => "xor",
+                            OprType::Lsh => "lsh",
+                            OprType::Rsh => "rsh",
+                            OprType::Zrsh => "zrsh",
+                            OprType::Insert => "_insert",
+                            OprType::Extract => "_extract",
+                            _ => return Err(ZError::t031(self.ty).with_span(self)),
                         }
                         .into(),
                         name_span: None,
@@ -106,7 +197,7 @@ impl AstData for BinaryOpr {
                     .into(),
                     paren_spans: None,
                     args: vec![self.operand2.desugared()?],
-                    kwargs: HashMap::default(),
+                    kwargs: vec![],
                 }
                 .desugared()?
             }
@@ -146,8 +237,22 @@ impl AstData for BinaryOpr {
                 }
             }
             OprType::TypeCast => {
+                if operand2 == Value::Type(Arc::clone(&OPT_T_VAL)) && operand1.as_opt().is_none() {
+                    return Ok(Value::Opt(Some(Box::new(operand1))));
+                }
+                if let Value::Type(ty) = &operand2 {
+                    if matches!(**ty, ValueType::Union(_)) && operand1.value_ty().is_subtype_of(ty) {
+                        return Ok(operand1);
+                    }
+                }
                 let opr1_ty = Arc::clone(&operand1.value_ty());
                 let namespace = opr1_ty.namespace();
+                // a value without `_typecast`, like an enum variant, can still be cast to `type`
+                if operand2 == Value::Type(Arc::clone(&TYPE_T_VAL))
+                    && !namespace.contains_key("_typecast")
+                {
+                    return Ok(Value::Type(opr1_ty));
+                }
                 let Some(f) = namespace.get("_typecast") else {
                     return Err(ZError::t005(&opr1_ty.to_type(), "_typecast"));
                 };
@@ -156,6 +261,23 @@ impl AstData for BinaryOpr {
                 };
                 proc.call(vec![operand1, operand2], val_symt)
             }
+            OprType::Istype | OprType::Isnttype => {
+                let is_type = operand1
+                    .value_ty()
+                    .is_subtype_of(&operand2.into_type().z()?);
+                Ok(Value::Bool(is_type == (self.ty == OprType::Istype)))
+            }
+            OprType::Is | OprType::Isnt => Ok(Value::Bool(
+                operand1.is_identical(&operand2) == (self.ty == OprType::Is),
+            )),
+            // deep equality, without calling `_eq`, so values of different types are never equal
+            OprType::Iseq | OprType::Isnteq => Ok(Value::Bool(
+                (operand1 == operand2) == (self.ty == OprType::Iseq),
+            )),
+            OprType::Handle => match operand1.into_res().z()? {
+                Ok(value) => Ok(*value),
+                Err(err) => operand2.into_proc().z()?.call(vec![*err], val_symt),
+            },
             _opr => panic!("{_opr:?}"),
         }
     }
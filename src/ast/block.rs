@@ -4,6 +4,7 @@ use tracing::debug;
 
 use crate::{
     ast::{Ast, AstData, Reconstruct},
+    errors::ZError,
     primitives::UNIT_T,
     types::{
         position::{GetSpan, Span},
@@ -65,12 +66,16 @@ impl Block {
         for ele in &mut self.content {
             last = ele.type_check(ty_symt)?;
         }
-        ty_symt.set_block_return(
-            Arc::clone(&last),
-            self.content.last().and_then(GetSpan::span),
-        )?;
+        // only a procedure body (which has no frame of its own here) returns its last value;
+        // `if`, `match` and loop bodies and bare blocks give it to the enclosing expression instead
         if add_set {
             ty_symt.pop_frame()?;
+        } else {
+            ty_symt.set_block_return(
+                Arc::clone(&last),
+                None,
+                self.content.last().and_then(GetSpan::span),
+            )?;
         }
         Ok(last)
     }
@@ -94,24 +99,35 @@ impl Block {
             val_symt.add_frame(InterpretFrameType::Normal);
         }
         for ele in &self.content {
-            if let Ast::Return(r#return) = ele {
-                if returnable {
-                    last = r#return.value.interpret_expr(val_symt)?;
-                } else {
-                    last = ele.interpret_expr(val_symt)?;
+            // a `ret` (or `?`) that does not target this block unwinds further as an error, so
+            // that it also leaves any expression that this block is part of
+            let (value, label) = match ele.interpret_expr(val_symt) {
+                Ok(Value::Return(value, label)) => (value, label),
+                Ok(value) => {
+                    last = value;
+                    if let Value::Break(_) | Value::Continue(_) = last {
+                        pop!();
+                        return Ok(last);
+                    }
+                    continue;
                 }
-                pop!();
-                return Ok(last);
-            }
-            last = ele.interpret_expr(val_symt)?;
-            if let Value::Return(value) = last {
-                pop!();
-                return if returnable {
-                    Ok(*value)
-                } else {
-                    Ok(Value::Return(value))
-                };
-            }
+                Err(ZError {
+                    propagated: Some(value),
+                    propagated_label: label,
+                    ..
+                }) => (value, label),
+                Err(err) => {
+                    pop!();
+                    return Err(err);
+                }
+            };
+            let is_target = returnable && (label.is_none() || val_symt.front_mut()?.label == label);
+            pop!();
+            return if is_target {
+                Ok(*value)
+            } else {
+                Err(ZError::i000(*value, label))
+            };
         }
         pop!();
         Ok(last)
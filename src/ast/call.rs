@@ -1,19 +1,20 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, mem, sync::Arc};
 
 use itertools::{Either, Itertools};
 use smol_str::SmolStr;
 use tracing::debug;
 
 use crate::{
-    ast::{Ast, AstData, BinaryOpr, Ident, Member, Reconstruct},
+    ast::{Ast, AstData, BinaryOpr, Ident, Member, Procedure, Reconstruct, Set},
     errors::{ToZResult, ZError},
-    primitives::{ANY_T, PROC_T, UNIT_T},
+    primitives::{generic_tup, LazyGenericProc, ANY_T, STR_T, TYPE_T, UNIT_T},
     types::{
         position::{GetSpan, Span},
-        r#type::TypeCheckType,
+        r#type::{Type, TypeArg, TypeCheckType},
         token::{AccessType, OprType},
+        value::Proc,
     },
-    InterpretSymTable, Type, TypeCheckSymTable, Value, ZResult,
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
 };
 
 #[derive(Clone, PartialEq, Debug)]
@@ -21,7 +22,9 @@ pub struct Call {
     pub called: Box<Ast>,
     pub paren_spans: Option<(Span, Span)>,
     pub args: Vec<Ast>,
-    pub kwargs: HashMap<SmolStr, Ast>,
+    /// `key: value` arguments in the order written, the key being an identifier or, for a
+    /// `hmap`, any expression
+    pub kwargs: Vec<(Ast, Ast)>,
 }
 impl GetSpan for Call {
     fn span(&self) -> Option<Span> {
@@ -60,31 +63,36 @@ impl AstData for Call {
             .iter_mut()
             .map(|a| a.type_check(ty_symt))
             .collect::<ZResult<Vec<_>>>()?;
-        let extract_proc = |ty: &Type| {
-            if let Type::Generic { type_args, base } = ty {
-                if !Arc::ptr_eq(base, &PROC_T) {
-                    None
-                } else if let Some((_, sig_arg_tys)) = type_args.iter().find(|(k, _)| *k == "A") {
-                    if let Some((_, ret_ty)) = type_args.iter().find(|(k, _)| *k == "R") {
-                        let Either::Right(Either::Left(sig_arg_tys)) = sig_arg_tys else {
-                        unreachable!()
-                    };
-                        let Either::Right(Either::Right(ret_ty)) = ret_ty else {
-                        unreachable!()
-                    };
-                        Some((sig_arg_tys.to_owned(), Arc::clone(ret_ty)))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        };
-        let (sig_arg_tys, ret_ty) = if let Some(res) = extract_proc(&called_type) {
-            res
+        if let Some(ty) = call_on_types(&self.called, &called_type, &arg_tys) {
+            return Ok(TypeCheckType::Const(ty));
+        }
+        // in the body of a generic proc, a member of a type parameter is only known once the proc
+        // is called, when the body is type-checked again
+        if matches!(**called_type, Type::Any) {
+            return Ok(Arc::clone(&ANY_T).into());
+        }
+        let callee_ty = Arc::clone(&called_type);
+        // calling a type constructs an instance of it with its `_new`, which is passed the type.
+        // The `_new` of a generic type gives the instance, whose type arguments depend on the
+        // arguments, and any other `_new` gives an instance of the type itself
+        let ctor = called_type.as_const().ok().and_then(|ty| {
+            let new_ty = Arc::clone(&**ty.namespace().get("_new")?);
+            let (sig_arg_tys, ret_ty) = new_ty.proc_sig()?;
+            let is_generic = matches!(&**ty, Type::Type { type_args, .. } if !type_args.is_empty());
+            Some((
+                (
+                    sig_arg_tys.get(1..)?.to_vec(),
+                    if is_generic { ret_ty } else { Arc::clone(ty) },
+                ),
+                new_ty,
+                Arc::clone(ty),
+            ))
+        });
+        let ctor_ty = ctor.as_ref().map(|(_, _, ty)| Arc::clone(ty));
+        let ((sig_arg_tys, ret_ty), proc_ty) = if let Some((res, new_ty, _)) = ctor {
+            (res, new_ty)
+        } else if let Some(res) = called_type.proc_sig() {
+            (res, Arc::clone(&called_type))
         } else {
             let mut ty = called_type;
             let mut out = None;
@@ -100,8 +108,8 @@ impl AstData for Call {
                     })
                     .desugared()?,
                 );
-                if let Some(res) = extract_proc(&f) {
-                    out = Some(res);
+                if let Some(res) = f.proc_sig() {
+                    out = Some((res, Arc::clone(&f)));
                     break;
                 }
                 ty = Arc::clone(&f).into();
@@ -112,14 +120,72 @@ impl AstData for Call {
                 return Err(ZError::t005(&ty, "_call"));
             }
         };
-        if arg_tys.len() != sig_arg_tys.len() {
+        let rest = proc_ty.proc_rest();
+        // keyword arguments are passed after the others as `(key, value)` pairs, an identifier key
+        // being its name, to a proc that takes any number of arguments
+        if rest.is_none() {
+            if let Some((key, value)) = self.kwargs.first() {
+                return Err(ZError::t029(&key.reconstruct()).with_span(value));
+            }
+        }
+        let mut arg_tys = arg_tys.iter().map(|ty| Arc::clone(ty)).collect::<Vec<_>>();
+        for (key, value) in &mut self.kwargs {
+            let key_ty = if let Ast::Ident(_) = key {
+                Arc::clone(&STR_T)
+            } else {
+                Arc::clone(&*key.type_check(ty_symt)?)
+            };
+            let value_ty = Arc::clone(&*value.type_check(ty_symt)?);
+            arg_tys.push(generic_tup(vec![key_ty, value_ty]));
+        }
+        if arg_tys.len() < sig_arg_tys.len()
+            || (rest.is_none() && arg_tys.len() != sig_arg_tys.len())
+        {
             return Err(ZError::t015(sig_arg_tys.len(), arg_tys.len()).with_span(&*self));
         }
+        let (arg_tys, rest_arg_tys) = arg_tys.split_at(sig_arg_tys.len());
+        let mut type_args = vec![];
+        for (arg_ty, sig_arg_ty) in arg_tys.iter().zip(&sig_arg_tys) {
+            sig_arg_ty.infer(arg_ty, &mut type_args);
+        }
+        if let Some(rest) = rest {
+            infer_rest(rest, rest_arg_tys, &mut type_args);
+        }
+        if rest.is_some() {
+            // the number of the other arguments, eg the length of the `arr` that `array(...)` gives
+            type_args.push(("n".into(), Either::Left(Value::Usize(rest_arg_tys.len()))));
+        }
+        let ret_ty = ret_ty.substitute(&type_args);
+        for param in ret_ty.params() {
+            // type parameters of an enclosing generic proc are still in scope
+            let in_scope = ty_symt.0.iter().any(|frame| {
+                matches!(
+                    frame.table.get(&param),
+                    Some(TypeCheckType::Const(ty)) if matches!(&**ty, Type::Param(p) if *p == param)
+                )
+            });
+            if !in_scope {
+                return Err(ZError::t026(&param).with_span(&*self));
+            }
+        }
         for (arg_ty, sig_arg_ty) in arg_tys.iter().zip(&sig_arg_tys) {
-            if !Arc::ptr_eq(arg_ty, sig_arg_ty) && !Arc::ptr_eq(sig_arg_ty, &ANY_T) {
-                return Err(ZError::t004(sig_arg_ty, arg_ty).with_span(&*self));
+            let sig_arg_ty = sig_arg_ty.substitute(&type_args);
+            if !arg_ty.is_assignable_to(&sig_arg_ty) {
+                return Err(ZError::t004(&sig_arg_ty, arg_ty).with_span(&*self));
+            }
+        }
+        if let Some(Either::Right(Either::Right(rest_ty))) = rest {
+            let rest_ty = rest_ty.substitute(&type_args);
+            for arg_ty in rest_arg_tys {
+                if !arg_ty.is_assignable_to(&rest_ty) {
+                    return Err(ZError::t004(&rest_ty, arg_ty).with_span(&*self));
+                }
             }
         }
+        if let Some(ctor_ty) = ctor_ty {
+            check_bounds(&ctor_ty, &type_args).map_err(|err| err.with_span(&*self))?;
+        }
+        Procedure::instantiate(&callee_ty, &type_args, ty_symt)?;
         Ok(ret_ty.into())
     }
 
@@ -164,7 +230,7 @@ impl AstData for Call {
             kwargs: self
                 .kwargs
                 .iter()
-                .map(|(k, v)| Ok((k.to_owned(), v.desugared()?)))
+                .map(|(k, v)| Ok((k.desugared()?, v.desugared()?)))
                 .collect::<ZResult<_>>()?,
         }))
     }
@@ -190,15 +256,209 @@ impl AstData for Call {
                 }
             }
         }
-        let proc = self.called.interpret_expr(val_symt)?.into_proc().z()?;
-        proc.call(
-            self.args
-                .iter()
-                .map(|a| a.interpret_expr(val_symt))
-                .collect::<ZResult<Vec<_>>>()?,
-            val_symt,
-        )
+        let called = self.called.interpret_expr(val_symt)?;
+        // a builtin that updates a variable takes it out of the table rather than copying it
+        let receiver_name = if let (
+            Value::Proc(Proc::Builtin {
+                updates_receiver: true,
+                ..
+            }),
+            Some(Ast::Ident(Ident { name, .. })),
+        ) = (&called, self.args.first())
+        {
+            Some(name)
+        } else {
+            None
+        };
+        let mut args = self
+            .args
+            .iter()
+            .skip(usize::from(receiver_name.is_some()))
+            .map(|a| a.interpret_expr(val_symt))
+            .collect::<ZResult<Vec<_>>>()?;
+        let taken = receiver_name.and_then(|name| {
+            args.insert(0, val_symt.take_val(name)?);
+            Some(name)
+        });
+        // a constant, which can't be taken, is copied as usual
+        if let (Some(_), None, Some(receiver)) = (receiver_name, taken, self.args.first()) {
+            args.insert(0, receiver.interpret_expr(val_symt)?);
+        }
+        for (key, value) in &self.kwargs {
+            let key = if let Ast::Ident(Ident { name, .. }) = key {
+                Value::Str(name.to_string())
+            } else {
+                key.interpret_expr(val_symt)?
+            };
+            args.push(Value::Tup(
+                vec![key, value.interpret_expr(val_symt)?].into(),
+            ));
+        }
+        let is_ctor = matches!(called, Value::Type(_));
+        let proc = if let Value::Type(ty) = called {
+            let new = ty.namespace().get("_new").cloned().z()?.into_proc().z()?;
+            // a `_new` taking `$` fills in a new instance, the others are passed the type
+            args.insert(
+                0,
+                if new.has_receiver() {
+                    Value::ClassInstance {
+                        ty,
+                        attrs: HashMap::new().into(),
+                    }
+                } else {
+                    Value::Type(ty)
+                },
+            );
+            new
+        } else {
+            called.into_proc().z()?
+        };
+        // the error of a builtin, which has no span of its own, is given the call's
+        let res = if let (Proc::Builtin { f, fail_err, .. }, Some(name)) = (&proc, taken) {
+            f(&mut args).map_or_else(
+                // the builtin leaves the receiver as it was, which goes back in its place
+                || {
+                    let err = fail_err(&args);
+                    val_symt.set_val(name, mem::replace(&mut args[0], Value::Unit), self)?;
+                    Err(err)
+                },
+                Ok,
+            )
+        } else {
+            proc.call(args, val_symt)
+        };
+        let res = res.map_err(|err| {
+            if err.pos.is_empty() {
+                err.with_span(self)
+            } else {
+                err
+            }
+        });
+        if let Proc::Builtin {
+            updates_receiver: true,
+            ..
+        }
+        | Proc::Defined {
+            updates_receiver: true,
+            ..
+        } = proc
+        {
+            let Value::Tup(mut res) = res? else {
+                unreachable!()
+            };
+            let (ret, receiver) = (res.pop().z()?, res.pop().z()?);
+            if is_ctor {
+                return Ok(receiver);
+            }
+            if let Some(receiver_ast) = self.args.first() {
+                // the receiver is written back like `Set` would, through any fields
+                let mut root = receiver_ast;
+                while let Ast::Member(Member {
+                    ty: AccessType::Field,
+                    parent,
+                    ..
+                }) = root
+                {
+                    root = parent;
+                }
+                match root {
+                    Ast::Ident(_) => Set::set(receiver_ast, receiver, val_symt)?,
+                    Ast::Index(_) => return Err(ZError::i005(receiver_ast).with_span(receiver_ast)),
+                    // a temporary, which nothing else refers to
+                    _ => (),
+                }
+            }
+            return Ok(ret);
+        }
+        res
+    }
+}
+
+/// Works out the type given by a builtin on types, like `T1 / T2`, called on types known at
+/// compile time
+fn call_on_types(
+    called: &Ast,
+    called_type: &TypeCheckType,
+    arg_tys: &[TypeCheckType],
+) -> Option<Arc<Type>> {
+    let Ast::Member(Member { name, .. }) = called else {
+        return None;
+    };
+    let f = TYPE_T.namespace().get(name)?.to_owned();
+    // the member may be one of another namespace with the same name
+    if !Arc::ptr_eq(&f, called_type) {
+        return None;
     }
+    let Some(Value::Proc(Proc::Builtin {
+        ty: LazyGenericProc {
+            type_fn: Some(type_fn),
+            ..
+        },
+        ..
+    })) = f.data
+    else {
+        return None;
+    };
+    let tys = arg_tys
+        .iter()
+        .map(|ty| ty.as_const().ok().cloned())
+        .collect::<Option<Vec<_>>>()?;
+    Some(type_fn(&tys))
+}
+
+/// Binds the type parameters in `rest`, the type of the arguments that a proc takes after its
+/// fixed ones, to the types of those arguments
+fn infer_rest(rest: &TypeArg, rest_arg_tys: &[Arc<Type>], type_args: &mut Vec<(SmolStr, TypeArg)>) {
+    match rest {
+        // every argument has the same type, so the first one binds its type parameters
+        Either::Right(Either::Right(rest_ty)) => {
+            for arg_ty in rest_arg_tys {
+                rest_ty.infer(arg_ty, type_args);
+            }
+            // with no arguments, as in `vec()`, they could be anything
+            for param in rest_ty.params() {
+                if !type_args.iter().any(|(k, _)| *k == param) {
+                    type_args.push((param, Either::Right(Either::Right(Arc::clone(&ANY_T)))));
+                }
+            }
+        }
+        Either::Right(Either::Left(params)) => {
+            for param in params {
+                if let Type::Param(name) = &**param {
+                    type_args.push((
+                        name.to_owned(),
+                        Either::Right(Either::Left(rest_arg_tys.to_vec())),
+                    ));
+                }
+            }
+        }
+        Either::Left(_) => (),
+    }
+}
+
+/// Checks the type arguments that a call to the `_new` of `ty` binds. A type parameter declared
+/// with a type other than `type` only takes the types that are assignable to it, like the numbers
+/// that a `cpx` is made of
+fn check_bounds(ty: &Type, type_args: &[(SmolStr, TypeArg)]) -> ZResult<()> {
+    let Type::Type {
+        type_args: params, ..
+    } = ty
+    else {
+        return Ok(());
+    };
+    for (name, bound) in params {
+        if bound.same_as(&TYPE_T) {
+            continue;
+        }
+        let arg = type_args.iter().find_map(|(k, v)| match v {
+            Either::Right(Either::Right(arg)) if k == name => Some(arg),
+            _ => None,
+        });
+        if let Some(arg) = arg.filter(|arg| !arg.is_assignable_to(bound)) {
+            return Err(ZError::t004(bound, arg));
+        }
+    }
+    Ok(())
 }
 
 impl Reconstruct for Call {
@@ -206,7 +466,13 @@ impl Reconstruct for Call {
         format!(
             "{} ( {} )",
             self.called.reconstruct(),
-            self.args.iter().map(Reconstruct::reconstruct).join(" , ")
+            self.args
+                .iter()
+                .map(Reconstruct::reconstruct)
+                .chain(self.kwargs.iter().map(|(key, value)| {
+                    format!("{} : {}", key.reconstruct(), value.reconstruct())
+                }))
+                .join(" , ")
         )
     }
 }
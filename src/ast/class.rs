@@ -1,17 +1,25 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    iter::once,
+    sync::{Arc, Mutex, PoisonError},
+};
 
 use itertools::Itertools;
 use smol_str::SmolStr;
 use tracing::debug;
 
 use crate::{
-    ast::{argument::Argument, Ast, AstData, Block, Reconstruct},
+    ast::{
+        argument::Argument, Ast, AstData, Block, Call, Ident, Member, Procedure, Reconstruct, Set,
+    },
     errors::{ToZResult, ZError},
+    primitives::{generic_proc, ANY_T, TYPE_T},
     types::{
         position::{GetSpan, Span},
-        r#type::{TypeCheckType, ValueType},
+        r#type::{EnumLink, LazyType, TypeCheckType, ValueType},
         sym_table::TypeCheckFrameType,
-        token::Flag,
+        token::{AccessType, Flag},
+        value::Proc,
     },
     InterpretSymTable, Type, TypeCheckSymTable, Value, ZResult,
 };
@@ -19,17 +27,29 @@ use crate::{
 #[derive(Clone, PartialEq, Debug)]
 pub enum Class {
     Raw {
+        /// The name the class is declared with, given by the declaration before it is type-checked
+        /// since the type cannot be renamed once its methods refer to it
+        name: Option<Ident>,
         is_struct: bool,
         kwd_span: Option<Span>,
         content: Option<Block>,
         args: Option<Vec<Argument>>,
     },
     TypeChecked {
+        name: Option<Ident>,
         is_struct: bool,
         span: Option<Span>,
         reconstruction: String,
         namespace: HashMap<SmolStr, Ast>,
         fields: HashMap<SmolStr, Arc<Type>>,
+        /// The fields that the generated `_new` takes, in order.
+        /// `None` if the class declares its own `_new` or has no args
+        ctor_fields: Option<Vec<SmolStr>>,
+        /// The classes inherited from with `..`
+        parents: Vec<Ast>,
+        /// For a class that inherits `_new` but has instance fields of its own, the number of
+        /// arguments `_new` takes and the fields that are set to their defaults after it runs
+        inherited_new: Option<(usize, Vec<(Ident, Ast)>)>,
     },
 }
 
@@ -47,6 +67,166 @@ impl GetSpan for Class {
     }
 }
 
+impl Class {
+    /// Builds the type of the class, which its methods can refer to as `$ty`,
+    /// then type-checks the methods
+    fn class_ty(
+        name: Option<Ident>,
+        mut namespace: HashMap<SmolStr, LazyType<Value>>,
+        fields: HashMap<SmolStr, Arc<Type>>,
+        parents: &[Arc<Type>],
+        methods: &mut [(SmolStr, &mut Ast)],
+        ty_symt: &mut TypeCheckSymTable,
+    ) -> ZResult<Arc<Type>> {
+        let method_tys: Arc<Mutex<HashMap<SmolStr, Arc<Type>>>> = Arc::default();
+        for (name, _) in methods.iter() {
+            let method_tys = Arc::clone(&method_tys);
+            let name = name.to_owned();
+            namespace.insert(
+                name.to_owned(),
+                LazyType::from_fn(move || {
+                    method_tys
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .get(&name)
+                        .cloned()
+                }),
+            );
+        }
+        let ty = Arc::new(Type::Type {
+            name,
+            namespace,
+            fields,
+            type_args: vec![],
+            parents: parents.to_owned(),
+            variant_of: EnumLink::default(),
+        });
+        ty_symt.declare_val("$ty", TypeCheckType::Const(Arc::clone(&ty)))?;
+
+        // a method that is used before it is type-checked has the type of its signature
+        for (name, method) in methods.iter() {
+            let Ast::Procedure(method) = &**method else {
+                unreachable!()
+            };
+            let method_ty = method.declared_ty(ty_symt)?;
+            method_tys
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(name.to_owned(), method_ty);
+        }
+        for (name, method) in methods.iter_mut() {
+            let method_ty = Arc::clone(&*method.type_check(ty_symt)?);
+            method_tys
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(name.to_owned(), method_ty);
+        }
+        Ok(ty)
+    }
+
+    /// Type-checks the class after `..`, which its namespace and fields are inherited from
+    fn parent_ty(parent: &mut Ast, ty_symt: &mut TypeCheckSymTable) -> ZResult<Arc<Type>> {
+        let ty = parent.type_check(ty_symt)?;
+        let Ok(parent_ty) = ty.as_const() else {
+            return Err(ZError::t033(&ty).with_span(&*parent));
+        };
+        // a class always has a `_new`, unlike the other types
+        if !matches!(**parent_ty, Type::Type { .. }) || !parent_ty.namespace().contains_key("_new")
+        {
+            return Err(ZError::t033(parent_ty).with_span(&*parent));
+        }
+        Ok(Arc::clone(parent_ty))
+    }
+
+    /// `$.field = default`
+    fn set_default(field: Ident, default: Ast) -> Ast {
+        Ast::Set(Set {
+            variable: Box::new(Ast::Member(Member {
+                ty: AccessType::Field,
+                name: field.name,
+                parent: Box::new(Ast::Ident(Ident {
+                    name: "$".into(),
+                    name_span: None,
+                })),
+                name_span: field.name_span,
+                dot_span: None,
+            })),
+            eq_span: None,
+            content: Box::new(default),
+        })
+    }
+
+    /// Wraps an inherited `_new`, so that the class's own fields are set to their defaults
+    /// after it constructs the instance
+    fn wrap_new(new: &Value, arg_count: usize, defaults: &[(Ident, Ast)]) -> Value {
+        let has_receiver = new.as_proc().is_some_and(Proc::has_receiver);
+        let args: Vec<SmolStr> = once("$".into())
+            .chain((0..arg_count).map(|i| format!("${i}").into()))
+            .collect();
+        let mut call_args = args
+            .iter()
+            .map(|arg| Ast::Ident(Ident::new(arg)))
+            .collect_vec();
+        // a `_new` that does not take `$` is passed the type and returns the instance instead
+        if !has_receiver {
+            call_args[0] = Ast::Ident(Ident::new("$ty"));
+        }
+        let call = Ast::Call(Call {
+            called: Box::new(new.as_ast()),
+            paren_spans: None,
+            args: call_args,
+            kwargs: vec![],
+        });
+        let init = if has_receiver {
+            call
+        } else {
+            Ast::Set(Set {
+                variable: Box::new(Ast::Ident(Ident::new("$"))),
+                eq_span: None,
+                content: Box::new(call),
+            })
+        };
+        Value::Proc(Proc::Defined {
+            is_fn: true,
+            label: None,
+            content: Block {
+                brace_spans: None,
+                content: once(init)
+                    .chain(defaults.iter().map(|(field, default)| {
+                        Self::set_default(field.to_owned(), default.to_owned())
+                    }))
+                    .collect(),
+            },
+            args,
+            updates_receiver: true,
+        })
+    }
+
+    /// Whether a method of type `ty` can replace the inherited one of type `inherited`.
+    /// The receiver is left out, as it is an instance of the class the method is in.
+    fn overrides(ty: &Arc<Type>, inherited: &Arc<Type>, has_receiver: bool) -> bool {
+        match (has_receiver, ty.proc_sig(), inherited.proc_sig()) {
+            (true, Some((args, ret)), Some((inherited_args, inherited_ret))) => {
+                args.len() == inherited_args.len()
+                    && inherited_args
+                        .iter()
+                        .zip(&args)
+                        .skip(1)
+                        .all(|(a, b)| a.is_assignable_to(b))
+                    && ret.is_assignable_to(&inherited_ret)
+            }
+            _ => ty.is_assignable_to(inherited),
+        }
+    }
+
+    fn ctor_ty(arg_tys: Vec<Arc<Type>>) -> Arc<Type> {
+        generic_proc(
+            once(Arc::clone(&TYPE_T)).chain(arg_tys).collect(),
+            Arc::clone(&ANY_T),
+        )
+    }
+}
+
 impl AstData for Class {
     fn as_variant(&self) -> Ast {
         Ast::Class(self.to_owned())
@@ -54,38 +234,94 @@ impl AstData for Class {
 
     fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
         debug!(span = ?self.span(), "Type-checking class declaration");
-        let (is_struct, content, args) = match self {
+        let (name, is_struct, content, args) = match self {
             Self::Raw {
+                name,
                 is_struct,
                 content,
                 args,
                 ..
-            } => (is_struct, content, args),
+            } => (name.to_owned(), is_struct, content, args),
             Self::TypeChecked {
-                namespace, fields, ..
+                name,
+                namespace,
+                fields,
+                ctor_fields,
+                parents,
+                ..
             } => {
                 let mut ty_symt = ty_symt.to_owned();
-                return Ok(Arc::new(Type::Type {
-                    name: None,
-                    namespace: namespace
-                        .iter_mut()
-                        .map(|(k, v)| {
-                            Ok((
-                                k.to_owned(),
-                                Arc::clone(&*v.type_check(&mut ty_symt)?).into(),
-                            ))
-                        })
-                        .collect::<ZResult<HashMap<_, _>>>()?,
-                    fields: fields.to_owned(),
-                    type_args: vec![],
-                })
-                .into());
+                let parent_tys = parents
+                    .iter_mut()
+                    .map(|parent| Self::parent_ty(parent, &mut ty_symt))
+                    .collect::<ZResult<Vec<_>>>()?;
+                ty_symt.add_frame(TypeCheckFrameType::Function(None));
+                let (mut methods, statics): (Vec<_>, Vec<_>) = namespace
+                    .iter_mut()
+                    .map(|(k, v)| (k.to_owned(), v))
+                    .partition(|(_, v)| matches!(v, Ast::Procedure(_)));
+                let mut namespace = statics
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, Arc::clone(&*v.type_check(&mut ty_symt)?).into())))
+                    .collect::<ZResult<HashMap<_, _>>>()?;
+                if let Some(ctor_fields) = ctor_fields {
+                    let arg_tys = ctor_fields
+                        .iter()
+                        .map(|name| Arc::clone(&fields[name]))
+                        .collect();
+                    namespace.insert("_new".into(), Self::ctor_ty(arg_tys).into());
+                }
+                for parent_ty in &parent_tys {
+                    for (k, v) in parent_ty.namespace().iter() {
+                        namespace
+                            .entry(k.to_owned())
+                            .or_insert_with(|| v.to_owned());
+                    }
+                }
+                let ty = Self::class_ty(
+                    name.to_owned(),
+                    namespace,
+                    fields.to_owned(),
+                    &parent_tys,
+                    &mut methods,
+                    &mut ty_symt,
+                )?;
+                return Ok(TypeCheckType::Const(ty));
             }
         };
         let mut namespace_ast = HashMap::new();
         let mut namespace_ty = HashMap::new();
+        // a class without `_new` is constructed with no arguments
+        let mut default_new = Ast::Procedure(Procedure {
+            is_fn: true,
+            kwd_span: None,
+            label: None,
+            type_params: vec![],
+            args: vec![Argument {
+                name: Ident {
+                    name: "$".into(),
+                    name_span: None,
+                },
+                ty: Box::new(Ast::Ident(Ident {
+                    name: "$ty".into(),
+                    name_span: None,
+                })),
+                default: None,
+            }],
+            updates_receiver: true,
+            return_type: None,
+            content: Block {
+                brace_spans: None,
+                content: vec![],
+            },
+        });
+        let mut methods = vec![];
+        let mut inst_defaults = vec![];
         let mut fields = HashMap::new();
         let mut new_span = None;
+        let mut inherited_new = None;
+        let mut parents = vec![];
+        let mut parent_tys = vec![];
 
         ty_symt.add_frame(TypeCheckFrameType::Function(None));
 
@@ -95,9 +331,37 @@ impl AstData for Class {
         } else {
             &mut empty
         };
+        // the parents are inherited from first, so that the class's own declarations replace them
+        for statement in statements.iter_mut() {
+            let Ast::Spread(spread) = statement else {
+                continue;
+            };
+            if *is_struct {
+                return Err(ZError::t034().with_span(&*spread));
+            }
+            let parent_ty = Self::parent_ty(&mut spread.content, ty_symt)?;
+            for (k, v) in parent_ty.fields().iter() {
+                fields.entry(k.to_owned()).or_insert_with(|| Arc::clone(v));
+            }
+            for (k, v) in parent_ty.namespace().iter() {
+                namespace_ty
+                    .entry(k.to_owned())
+                    .or_insert_with(|| v.to_owned());
+            }
+            parent_tys.push(parent_ty);
+            parents.push(*spread.content.to_owned());
+        }
+        let inherited_fields = fields.to_owned();
+        let inherited_namespace = namespace_ty
+            .iter()
+            .map(|(k, v)| (k.to_owned(), Arc::clone(&**v)))
+            .collect::<HashMap<_, _>>();
+
         for statement in statements {
-            let ty = statement.type_check(ty_symt)?;
-            let Ast::Declare(dec) = statement else {
+            if matches!(statement, Ast::Spread(_)) {
+                continue;
+            }
+            let Ast::Declare(dec) = &*statement else {
                 return Err(ZError::t013().with_span(&*statement))
             };
             let Ast::Ident(ident) = *dec.variable.to_owned() else {
@@ -109,7 +373,29 @@ impl AstData for Class {
                 }
                 new_span = Some(ident.span());
             }
-            if dec.flags.iter().any(|(k, _)| *k == Flag::Inst) {
+            let is_inst = dec.flags.iter().any(|(k, _)| *k == Flag::Inst);
+            // methods are type-checked once the fields are known, as they may use `$ty`
+            if !is_inst && matches!(*dec.content, Ast::Procedure(_)) {
+                let Ast::Declare(dec) = statement else {
+                    unreachable!()
+                };
+                methods.push((ident.name, &mut *dec.content));
+                continue;
+            }
+            let ty = statement.type_check(ty_symt)?;
+            let Ast::Declare(dec) = &*statement else {
+                unreachable!()
+            };
+            let inherited = if is_inst {
+                inherited_fields.get(&ident.name)
+            } else {
+                inherited_namespace.get(&ident.name)
+            };
+            if let Some(inherited) = inherited.filter(|inherited| !ty.is_assignable_to(inherited)) {
+                return Err(ZError::t035(&ident.name, inherited, &ty).with_span(ident));
+            }
+            if is_inst {
+                inst_defaults.push((ident.to_owned(), *dec.content.to_owned()));
                 fields.insert(ident.name, Arc::clone(&*ty));
             } else {
                 namespace_ty.insert(ident.name.to_owned(), Arc::clone(&*ty).into());
@@ -126,7 +412,7 @@ impl AstData for Class {
         } else {
             &mut empty2
         };
-        let _arg_tys = args
+        let arg_tys = args
             .iter_mut()
             .map(|arg| {
                 let arg_ty = arg.type_check(ty_symt)?;
@@ -135,37 +421,82 @@ impl AstData for Class {
             })
             .collect::<ZResult<Vec<_>>>()?;
 
-        let ty = Arc::new(Type::Type {
-            name: None,
-            namespace: namespace_ty,
-            fields: fields.to_owned(),
-            type_args: vec![],
+        // `struct |a: T, b: U|` is constructed with `a` and `b`, in order
+        let ctor_fields = (new_span.is_none() && (*is_struct || !args.is_empty())).then(|| {
+            namespace_ty.insert("_new".into(), Self::ctor_ty(arg_tys).into());
+            args.iter().map(|arg| arg.name.name.to_owned()).collect()
         });
 
-        if new_span.is_none() { // todo when `$` is added
-             /*let Some(Type::Type { namespace, ..}) = Arc::get_mut(&mut ty) else {
-                 unreachable!()
-             };
-             namespace.insert("_new".into(), generic_proc(arg_tys, Arc::clone(&ty)).into());
-             namespace_ast.insert("_new".into(), Value::Proc(Proc::Defined {
-                 is_fn: false,
-                 content: Block { brace_spans: None, content: vec![
+        if !*is_struct && new_span.is_none() {
+            if parent_tys.is_empty() {
+                methods.push(("_new".into(), &mut default_new));
+            } else if !inst_defaults.is_empty() {
+                // the inherited `_new` does not know about the fields of the class,
+                // so they are set after it runs
+                let arg_count = inherited_namespace["_new"]
+                    .proc_sig()
+                    .map_or(0, |(args, _)| args.len().saturating_sub(1));
+                inherited_new = Some((arg_count, inst_defaults.to_owned()));
+            }
+        }
+        if !*is_struct {
+            // the fields of a new instance start with their defaults, before `_new` sets them
+            if let Some((_, Ast::Procedure(new))) =
+                methods.iter_mut().find(|(name, _)| name == "_new")
+            {
+                if new.args.first().is_some_and(|arg| arg.name.name == "$") {
+                    new.content.content.splice(
+                        0..0,
+                        inst_defaults
+                            .into_iter()
+                            .map(|(field, default)| Self::set_default(field, default)),
+                    );
+                }
+            }
+        }
 
-                 ] },
-                 args: vec![],
-             }).as_ast().into());*/
+        let ty = Self::class_ty(
+            name.to_owned(),
+            namespace_ty,
+            fields.to_owned(),
+            &parent_tys,
+            &mut methods,
+            ty_symt,
+        )?;
+        for (name, method) in &methods {
+            // `_new` is not called on instances, so it can take anything
+            let Some(inherited) = inherited_namespace.get(name).filter(|_| name != "_new") else {
+                continue;
+            };
+            let Ast::Procedure(proc) = &**method else {
+                unreachable!()
+            };
+            let has_receiver = proc.args.first().is_some_and(|arg| arg.name.name == "$");
+            let method_ty = Arc::clone(&ty.namespace()[name]);
+            if !Self::overrides(&method_ty, inherited, has_receiver) {
+                return Err(ZError::t035(name, inherited, &method_ty).with_span(&**method));
+            }
         }
+        namespace_ast.extend(
+            methods
+                .into_iter()
+                .map(|(name, method)| (name, method.to_owned())),
+        );
 
         *self = Self::TypeChecked {
+            name,
             is_struct: *is_struct,
             reconstruction: self.reconstruct(),
             span: self.span(),
             namespace: namespace_ast,
             fields,
+            ctor_fields,
+            parents,
+            inherited_new,
         };
 
         ty_symt.pop_frame()?;
-        Ok(ty.into())
+        Ok(TypeCheckType::Const(ty))
     }
 
     fn desugared(&self) -> ZResult<Ast> {
@@ -192,19 +523,51 @@ impl AstData for Class {
     }
 
     fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
-        let Self::TypeChecked { namespace, fields, .. } = self else {
+        let Self::TypeChecked {
+            name,
+            namespace,
+            fields,
+            ctor_fields,
+            parents,
+            inherited_new,
+            ..
+        } = self
+        else {
             unreachable!()
         };
-        let namespace = namespace
+        let mut namespace = namespace
             .iter()
             .map(|(k, v)| Ok((k.to_owned(), v.interpret_expr(val_symt)?)))
             .collect::<ZResult<HashMap<_, _>>>()?;
-        Ok(Value::Type(Arc::new(ValueType::Type {
-            name: None,
+        if let Some(ctor_fields) = ctor_fields {
+            namespace.insert(
+                "_new".into(),
+                Value::Proc(Proc::ctor(ctor_fields.to_owned())),
+            );
+        }
+        let parents = parents
+            .iter()
+            .map(|parent| parent.interpret_expr(val_symt)?.into_type().z())
+            .collect::<ZResult<Vec<_>>>()?;
+        for parent in &parents {
+            for (k, v) in parent.namespace().iter() {
+                namespace
+                    .entry(k.to_owned())
+                    .or_insert_with(|| v.to_owned());
+            }
+        }
+        if let Some((arg_count, defaults)) = inherited_new {
+            let new = namespace.remove("_new").z()?;
+            namespace.insert("_new".into(), Self::wrap_new(&new, *arg_count, defaults));
+        }
+        let ty = Arc::new(ValueType::Type {
+            name: name.to_owned(),
             namespace,
             fields: fields.to_owned(),
             type_args: vec![],
-        })))
+            parents,
+        });
+        Ok(Value::Type(ty))
     }
 }
 
@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use tracing::debug;
 
 use crate::{
-    ast::{Ast, AstData, BinaryOpr, Reconstruct},
+    ast::{Ast, AstData, BinaryOpr, Class, Enum, Reconstruct},
     errors::ToZResult,
     types::{
         position::{GetSpan, Span},
@@ -39,24 +41,32 @@ impl AstData for Declare {
         if !self.variable.is_pattern() {
             return Err(ZError::t006().with_span(&self.variable));
         }
+        // a class is named before it is type-checked, as its methods can refer to its type, and an
+        // enum so that its variants are named `E::A`
+        if let (
+            Ast::Class(Class::Raw { name, .. }) | Ast::Enum(Enum { name, .. }),
+            Ast::Ident(ident),
+        ) = (&mut *self.content, &*self.variable)
+        {
+            *name = Some(ident.to_owned());
+        }
         let mut content_type = self.content.type_check(ty_symt)?;
         let ty = self
             .ty
-            .as_ref()
-            .map(|ty| {
-                let Ast::Ident(i) = &**ty else {
-                return Err(ZError::t008().with_span(&self.ty))
-            };
-                ty_symt.get_type(&i.name, ty.span())
-            })
+            .as_mut()
+            .map(|ty| Ok(Arc::clone(ty.type_check(ty_symt)?.as_const()?)))
             .transpose()?;
         let name = if let Ast::Ident(ident) = &*self.variable {
             ident.name.to_owned()
         } else {
             return Err(ZError::t008().with_span(&self.variable));
         };
-        if let Some(ty) = ty {
-            if *content_type != ty {
+        if let Some(ty) = ty.filter(|ty| !content_type.same_as(ty)) {
+            if content_type.is_assignable_to(&ty) {
+                content_type = ty.into();
+            } else if !content_type.namespace().contains_key("_typecast") {
+                return Err(ZError::t011(&ty, &content_type).with_span(&*self.content));
+            } else {
                 let mut new_content = BinaryOpr {
                     ty: OprType::TypeCast,
                     opr_span: None,
@@ -64,7 +74,7 @@ impl AstData for Declare {
                     operand2: self.ty.to_owned().unwrap_or_else(|| unreachable!()),
                 }
                 .as_variant();
-                new_content.type_check(ty_symt)?;
+                content_type = new_content.type_check(ty_symt)?;
                 *self = Self {
                     ty: self.ty.to_owned(),
                     content: new_content.into(),
@@ -0,0 +1,245 @@
+use std::{collections::HashMap, iter::once, sync::Arc};
+
+use itertools::Itertools;
+use smol_str::SmolStr;
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Class, Ident, Reconstruct},
+    errors::ZError,
+    primitives::{generic_proc, tup_items, LazyGenericProc, ANY_T, BOOL_T, TYPE_T},
+    types::{
+        position::{GetSpan, Span},
+        r#type::{EnumLink, Type, TypeCheckType, ValueType},
+        value::{BuiltinFunction, Proc},
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct EnumVariant {
+    pub name: Ident,
+    pub value: Option<Ast>,
+    pub ty: Option<Ast>,
+    /// The fields of the variant in order, known once the enum is type-checked.
+    /// `inst A := value` has a `value` field, `inst A: struct |x: T|` has the struct's fields and
+    /// `inst A: tu[T, U]` has `_0` and `_1`
+    pub fields: Vec<(SmolStr, Arc<Type>)>,
+}
+impl GetSpan for EnumVariant {
+    fn span(&self) -> Option<Span> {
+        self.name.merge_span(&self.value).merge_span(&self.ty)
+    }
+}
+
+impl EnumVariant {
+    /// The name of the variant's type, qualified as `E::A` if the enum is named `E`
+    fn type_name(&self, enum_name: Option<&Ident>) -> Ident {
+        let Some(enum_name) = enum_name else {
+            return self.name.to_owned();
+        };
+        Ident {
+            name: format!("{}::{}", enum_name.name, self.name.name).into(),
+            name_span: self.name.name_span.to_owned(),
+        }
+    }
+
+    fn type_check(
+        &mut self,
+        enum_name: Option<&Ident>,
+        ty_symt: &mut TypeCheckSymTable,
+    ) -> ZResult<Type> {
+        debug!(span = ?self.span(), "Type-checking enum variant");
+        let eq_ty = generic_proc(vec![Arc::clone(&ANY_T); 2], Arc::clone(&BOOL_T));
+        let mut namespace: HashMap<SmolStr, _> = HashMap::from([
+            ("_eq".into(), Arc::clone(&eq_ty).into()),
+            ("_ne".into(), eq_ty.into()),
+        ]);
+        if let Some(value) = &mut self.value {
+            let value_ty = Arc::clone(&*value.type_check(ty_symt)?);
+            namespace.insert("value".into(), Arc::clone(&value_ty).into());
+            self.fields = vec![("value".into(), value_ty)];
+        }
+        if let Some(ty) = &mut self.ty {
+            // the order of a struct's fields is lost once it is type-checked
+            let field_names = if let Ast::Class(Class::Raw {
+                is_struct: true,
+                args: Some(args),
+                ..
+            }) = ty
+            {
+                Some(
+                    args.iter()
+                        .map(|arg| arg.name.name.to_owned())
+                        .collect_vec(),
+                )
+            } else {
+                None
+            };
+            let ty = Arc::clone(ty.type_check(ty_symt)?.as_const()?);
+            self.fields = if let Some(field_names) = field_names {
+                let field_tys = ty.fields();
+                field_names
+                    .into_iter()
+                    .map(|name| {
+                        let field_ty = Arc::clone(&field_tys[&name]);
+                        (name, field_ty)
+                    })
+                    .collect()
+            } else if let Some(item_tys) = tup_items(&ty) {
+                item_tys
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item_ty)| (format!("_{i}").into(), item_ty))
+                    .collect()
+            } else {
+                vec![("_0".into(), ty)]
+            };
+            namespace.insert(
+                "_new".into(),
+                generic_proc(
+                    once(Arc::clone(&TYPE_T))
+                        .chain(self.fields.iter().map(|(_, ty)| Arc::clone(ty)))
+                        .collect(),
+                    Arc::clone(&ANY_T),
+                )
+                .into(),
+            );
+        }
+        Ok(Type::Type {
+            name: Some(self.type_name(enum_name)),
+            namespace,
+            fields: self.fields.iter().cloned().collect(),
+            type_args: vec![],
+            parents: vec![],
+            variant_of: EnumLink::default(),
+        })
+    }
+
+    fn interpret(
+        &self,
+        enum_name: Option<&Ident>,
+        val_symt: &mut InterpretSymTable,
+    ) -> ZResult<Arc<ValueType>> {
+        let mut namespace = HashMap::from([
+            ("_eq".into(), eq_proc(true)),
+            ("_ne".into(), eq_proc(false)),
+        ]);
+        if let Some(value) = &self.value {
+            namespace.insert("value".into(), value.interpret_expr(val_symt)?);
+        } else if self.ty.is_some() {
+            let field_names = self
+                .fields
+                .iter()
+                .map(|(name, _)| name.to_owned())
+                .collect();
+            namespace.insert("_new".into(), Value::Proc(Proc::ctor(field_names)));
+        }
+        Ok(Arc::new(ValueType::Type {
+            name: Some(self.type_name(enum_name)),
+            namespace,
+            fields: self.fields.iter().cloned().collect(),
+            type_args: vec![],
+            parents: vec![],
+        }))
+    }
+}
+
+/// The `_eq` (or `_ne` if `eq` is false) of a variant, comparing it to a value of any variant
+fn eq_proc(eq: bool) -> Value {
+    let f: Arc<BuiltinFunction> =
+        Arc::new(move |x: &mut Vec<Value>| Some(Value::Bool((x[0] == x[1]) == eq)));
+    Value::Proc(Proc::Builtin {
+        id: Arc::as_ptr(&f) as *const () as usize,
+        f,
+        ty: LazyGenericProc::new(vec![&ANY_T, &ANY_T], &BOOL_T),
+        updates_receiver: false,
+        fail_err: ZError::i001,
+    })
+}
+
+impl Reconstruct for EnumVariant {
+    fn reconstruct(&self) -> String {
+        if let Some(value) = &self.value {
+            format!(
+                "inst {} := {}",
+                self.name.reconstruct(),
+                value.reconstruct()
+            )
+        } else if let Some(ty) = &self.ty {
+            format!("inst {} : {}", self.name.reconstruct(), ty.reconstruct())
+        } else {
+            format!("inst {}", self.name.reconstruct())
+        }
+    }
+}
+
+/// An enum is the union of its variants, each of which is its own type
+#[derive(Clone, PartialEq, Debug)]
+pub struct Enum {
+    pub kwd_span: Option<Span>,
+    pub brace_spans: Option<(Span, Span)>,
+    pub variants: Vec<EnumVariant>,
+    /// The name the enum is declared with, which qualifies its variants, eg `E::A`
+    pub name: Option<Ident>,
+}
+impl GetSpan for Enum {
+    fn span(&self) -> Option<Span> {
+        let end_brace = self.brace_spans.as_ref().map(|a| &a.1);
+        self.kwd_span.merge_span(end_brace)
+    }
+}
+
+impl AstData for Enum {
+    fn as_variant(&self) -> Ast {
+        Ast::Enum(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking enum");
+        if let Some(dup) = self.variants.iter().duplicates_by(|v| &v.name.name).next() {
+            return Err(ZError::t032(&dup.name.name).with_span(&dup.name));
+        }
+        let variant_tys = self
+            .variants
+            .iter_mut()
+            .map(|variant| variant.type_check(self.name.as_ref(), ty_symt))
+            .collect::<ZResult<Vec<_>>>()?;
+        Ok(TypeCheckType::Const(Type::new_enum(variant_tys)))
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring enum");
+        let mut new_self = self.to_owned();
+        for variant in &mut new_self.variants {
+            if let Some(value) = &mut variant.value {
+                value.desugar()?;
+            }
+            if let Some(ty) = &mut variant.ty {
+                ty.desugar()?;
+            }
+        }
+        Ok(new_self.as_variant())
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let variants = self
+            .variants
+            .iter()
+            .map(|variant| variant.interpret(self.name.as_ref(), val_symt))
+            .collect::<ZResult<Vec<_>>>()?;
+        Ok(Value::Type(Arc::new(ValueType::Union(variants))))
+    }
+}
+
+impl Reconstruct for Enum {
+    fn reconstruct(&self) -> String {
+        format!(
+            "enum {{ {} }}",
+            self.variants
+                .iter()
+                .map(Reconstruct::reconstruct)
+                .join(" ; ")
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, BinaryOpr, Block, Call, Declare, Ident, Member, Reconstruct, Set, While},
+    types::{
+        position::{GetSpan, Span},
+        token::{AccessType, OprType},
+    },
+    Value, ZResult,
+};
+
+// the `'` keeps it from clashing with idents in code
+const ITER_VAR: &str = "for'iter";
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct For {
+    pub kwd_span: Option<Span>,
+    pub label: Option<Ident>,
+    pub variable: Ident,
+    pub in_span: Option<Span>,
+    pub iterable: Box<Ast>,
+    pub content: Block,
+}
+impl GetSpan for For {
+    fn span(&self) -> Option<Span> {
+        self.kwd_span
+            .merge_span(&self.label)
+            .merge_span(&self.variable)
+            .merge_span(&self.in_span)
+            .merge_span(&self.iterable)
+            .merge_span(&self.content)
+    }
+}
+
+impl AstData for For {
+    fn as_variant(&self) -> Ast {
+        Ast::For(self.to_owned())
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring for loop");
+        let iter_var = Ident {
+            name: ITER_VAR.into(),
+            name_span: self.iterable.span(),
+        };
+        let protocol_call = |name: &str, parent: Ast| {
+            Call {
+                called: Member {
+                    ty: AccessType::Method,
+                    name: name.into(),
+                    parent: parent.into(),
+                    name_span: None,
+                    dot_span: None,
+                }
+                .as_variant()
+                .into(),
+                paren_spans: None,
+                args: vec![],
+                kwargs: vec![],
+            }
+            .as_variant()
+        };
+
+        // advance before running the body so that leaving the body early can't skip it
+        let mut content = vec![
+            Declare {
+                variable: self.variable.as_variant().into(),
+                content: protocol_call("_item", iter_var.as_variant()).into(),
+                flags: vec![],
+                ty: None,
+                eq_span: None,
+            }
+            .as_variant(),
+            Set {
+                variable: iter_var.as_variant().into(),
+                eq_span: None,
+                content: protocol_call("_next", iter_var.as_variant()).into(),
+            }
+            .as_variant(),
+        ];
+        content.extend(self.content.content.to_owned());
+
+        While {
+            is_do_while: false,
+            label: self.label.to_owned(),
+            kwd_span: self.kwd_span.to_owned(),
+            do_kwd_span: None,
+            condition: BinaryOpr {
+                ty: OprType::Eq,
+                opr_span: None,
+                operand1: protocol_call("_done", iter_var.as_variant()).into(),
+                operand2: Value::Bool(false).as_ast().into(),
+            }
+            .as_variant()
+            .into(),
+            content: Block {
+                brace_spans: self.content.brace_spans.to_owned(),
+                content,
+            },
+            init: Some(
+                Declare {
+                    variable: iter_var.as_variant().into(),
+                    content: protocol_call("_iter", *self.iterable.to_owned()).into(),
+                    flags: vec![],
+                    ty: None,
+                    eq_span: None,
+                }
+                .as_variant()
+                .into(),
+            ),
+        }
+        .desugared()
+    }
+}
+
+impl Reconstruct for For {
+    fn reconstruct(&self) -> String {
+        format!(
+            "for{} {} in {} {}",
+            self.label
+                .as_ref()
+                .map_or_else(String::new, |label| format!("'{}", label.reconstruct())),
+            self.variable.reconstruct(),
+            self.iterable.reconstruct(),
+            self.content.reconstruct()
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, BinaryOpr, Ident, Literal, Reconstruct},
+    types::{
+        position::{GetSpan, Span},
+        token::OprType,
+    },
+    Value, ZResult,
+};
+
+/// `f"...{expr}..."`, which desugars to the string parts concatenated with each `expr @ str`
+#[derive(Clone, PartialEq, Debug)]
+pub struct FormatString {
+    pub parts: Vec<Ast>,
+    pub span: Option<Span>,
+}
+impl GetSpan for FormatString {
+    fn span(&self) -> Option<Span> {
+        self.span.span()
+    }
+}
+
+impl AstData for FormatString {
+    fn as_variant(&self) -> Ast {
+        Ast::FormatString(self.to_owned())
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring formatted string");
+        let parts = self.parts.iter().map(|part| match part {
+            Ast::Literal(Literal {
+                content: Value::Str(_),
+                ..
+            }) => part.to_owned(),
+            _ => BinaryOpr {
+                ty: OprType::TypeCast,
+                opr_span: None,
+                operand1: part.to_owned().into(),
+                operand2: Ast::Ident(Ident::new("str")).into(),
+            }
+            .as_variant(),
+        });
+        parts
+            .reduce(|acc, part| {
+                BinaryOpr {
+                    ty: OprType::Concat,
+                    opr_span: None,
+                    operand1: acc.into(),
+                    operand2: part.into(),
+                }
+                .as_variant()
+            })
+            .unwrap_or_else(|| Value::Str(String::new()).as_ast())
+            .desugared()
+    }
+}
+
+impl Reconstruct for FormatString {
+    fn reconstruct(&self) -> String {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                Ast::Literal(Literal {
+                    content: Value::Str(s),
+                    ..
+                }) => s.escape_default().to_string(),
+                _ => format!("{{{}}}", part.reconstruct()),
+            })
+            .join("");
+        format!("f\"{parts}\"")
+    }
+}
@@ -1,13 +1,15 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
+use smol_str::SmolStr;
 use tracing::debug;
 
 use crate::{
     ast::{Ast, AstData, Condition, Reconstruct},
-    errors::ZError,
+    errors::ToZResult,
     types::{
         position::{GetSpan, Span},
-        r#type::TypeCheckType,
+        r#type::{Type, TypeCheckType},
+        sym_table::TypeCheckFrameType,
     },
     InterpretSymTable, TypeCheckSymTable, Value, ZResult,
 };
@@ -32,18 +34,29 @@ impl AstData for If {
     }
     fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
         debug!(span = ?self.span(), "Type-checking if expression");
-        let mut first_ty: Option<TypeCheckType> = None;
-        for ty in &mut self.conditions {
-            let ty = ty.if_true.block_type(ty_symt, true)?;
-            if let Some(first_ty) = &first_ty {
-                if !Arc::ptr_eq(first_ty, &ty) {
-                    return Err(ZError::t011(first_ty, &ty));
+        let mut first_ty = None;
+        // types that variables were narrowed to by `istype` / `isnttype` in earlier conditions
+        // being false
+        let mut else_narrowings: HashMap<SmolStr, Arc<Type>> = HashMap::new();
+        for cond in &mut self.conditions {
+            ty_symt.add_frame(TypeCheckFrameType::Normal);
+            for (name, ty) in &else_narrowings {
+                ty_symt.declare_val(name, Arc::clone(ty).into())?;
+            }
+            if let Some(condition) = &mut cond.condition {
+                condition.type_check(ty_symt)?;
+                if let Ast::BinaryOpr(condition) = condition {
+                    if let Some((name, if_true, if_false)) = condition.narrowing(ty_symt)? {
+                        ty_symt.declare_val(&name, if_true.into())?;
+                        else_narrowings.insert(name, if_false);
+                    }
                 }
-            } else {
-                first_ty = Some(ty)
             }
+            let ty = cond.if_true.block_type(ty_symt, true)?;
+            ty_symt.pop_frame()?;
+            TypeCheckType::check_branch(&mut first_ty, ty, &cond.if_true)?;
         }
-        self.conditions[0].if_true.block_type(ty_symt, true)
+        first_ty.z()
     }
 
     fn desugared(&self) -> ZResult<Ast> {
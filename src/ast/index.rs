@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use itertools::{Either, Itertools};
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Literal, Reconstruct},
+    errors::{ToZResult, ZError},
+    primitives::{
+        generic_tup, tup_items, HMAP_T, HSET_T, I32_T, TUP_T, TYPE_T, UNION_T, UNION_T_VAL,
+    },
+    types::{
+        position::{GetSpan, Span},
+        r#type::{Type, TypeCheckType, ValueType},
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Index {
+    pub parent: Box<Ast>,
+    pub bracket_spans: Option<(Span, Span)>,
+    pub args: Vec<Ast>,
+}
+impl GetSpan for Index {
+    fn span(&self) -> Option<Span> {
+        let start_bracket = self.bracket_spans.as_ref().map(|a| &a.0);
+        let end_bracket = self.bracket_spans.as_ref().map(|a| &a.1);
+        self.parent
+            .merge_span(start_bracket)
+            .merge_span(&self.args)
+            .merge_span(end_bracket)
+    }
+}
+
+impl Index {
+    /// Type-checks indexing into a value, eg `v[0]`, rather than giving a type its type arguments
+    fn type_check_item(
+        &mut self,
+        parent_ty: &TypeCheckType,
+        ty_symt: &mut TypeCheckSymTable,
+    ) -> ZResult<TypeCheckType> {
+        if self.args.len() != 1 {
+            return Err(ZError::t015(1, self.args.len()).with_span(&*self));
+        }
+        let index_ty = self.args[0].type_check(ty_symt)?;
+        if let Some(items) = tup_items(parent_ty) {
+            if !index_ty.is_assignable_to(&I32_T) {
+                return Err(ZError::t011(&I32_T, &index_ty).with_span(&self.args[0]));
+            }
+            // the item type is only known if the index is
+            let Ast::Literal(Literal {
+                content: Value::I32(i),
+                ..
+            }) = &self.args[0]
+            else {
+                return Ok(Type::union(items).into());
+            };
+            return usize::try_from(*i)
+                .ok()
+                .and_then(|i| items.get(i))
+                .map(|ty| Arc::clone(ty).into())
+                .ok_or_else(|| ZError::t028(*i, items.len()).with_span(&*self));
+        }
+        let Some((arg_tys, ret_ty)) = parent_ty
+            .namespace()
+            .get("_index")
+            .and_then(|f| f.proc_sig())
+        else {
+            return Err(ZError::t005(parent_ty, "_index").with_span(&*self));
+        };
+        if !index_ty.is_assignable_to(&arg_tys[1]) {
+            return Err(ZError::t004(&arg_tys[1], &index_ty).with_span(&self.args[0]));
+        }
+        Ok(ret_ty.into())
+    }
+}
+
+impl AstData for Index {
+    fn as_variant(&self) -> Ast {
+        Ast::Index(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking index");
+        let parent_ty = self.parent.type_check(ty_symt)?;
+        let Ok(base) = parent_ty.as_const() else {
+            return self.type_check_item(&parent_ty, ty_symt);
+        };
+        let base = Arc::clone(base);
+        if Arc::ptr_eq(&base, &UNION_T) || Arc::ptr_eq(&base, &TUP_T) {
+            let members = self
+                .args
+                .iter_mut()
+                .map(|arg| Ok(Arc::clone(arg.type_check(ty_symt)?.as_const()?)))
+                .collect::<ZResult<Vec<_>>>()?;
+            return Ok(TypeCheckType::Const(if Arc::ptr_eq(&base, &UNION_T) {
+                Type::union(members)
+            } else {
+                generic_tup(members)
+            }));
+        }
+        let Type::Type { type_args, .. } = &*base else {
+            return Err(ZError::t023(&base).with_span(&*self));
+        };
+        if type_args.is_empty() {
+            return Err(ZError::t023(&base).with_span(&*self));
+        }
+        if type_args.len() != self.args.len() {
+            return Err(
+                ZError::t024(&base, type_args.len(), self.args.len()).with_span(&*self)
+            );
+        }
+        let args = type_args
+            .iter()
+            .zip(&mut self.args)
+            .map(|((name, arg_ty), arg)| {
+                if Arc::ptr_eq(arg_ty, &TYPE_T) {
+                    let arg_ty = Arc::clone(arg.type_check(ty_symt)?.as_const()?);
+                    return Ok((name.to_owned(), Either::Right(Either::Right(arg_ty))));
+                }
+                // other type arguments, like the length of an `arr`, are sizes
+                let Ast::Literal(Literal {
+                    content: Value::I32(n),
+                    ..
+                }) = arg
+                else {
+                    return Err(ZError::t027(name).with_span(&*arg));
+                };
+                let n = usize::try_from(*n)
+                    .ok()
+                    .ok_or_else(|| ZError::t027(name).with_span(&*arg))?;
+                Ok((name.to_owned(), Either::Left(Value::Usize(n))))
+            })
+            .collect::<ZResult<Vec<_>>>()?;
+        if Arc::ptr_eq(&base, &HMAP_T) || Arc::ptr_eq(&base, &HSET_T) {
+            if let Some((_, Either::Right(Either::Right(key_ty)))) = args.first() {
+                if !key_ty.is_hashable() {
+                    return Err(ZError::t005(key_ty, "_hash").with_span(&self.args[0]));
+                }
+            }
+        }
+        Ok(TypeCheckType::Const(Type::generic(&base, args)))
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring index");
+        Ok(Self {
+            parent: self.parent.desugared()?.into(),
+            bracket_spans: self.bracket_spans.to_owned(),
+            args: self
+                .args
+                .iter()
+                .map(AstData::desugared)
+                .collect::<ZResult<_>>()?,
+        }
+        .as_variant())
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let parent = self.parent.interpret_expr(val_symt)?;
+        if parent == Value::Type(Arc::clone(&UNION_T_VAL)) {
+            let members = self
+                .args
+                .iter()
+                .map(|arg| arg.interpret_expr(val_symt)?.into_type().z())
+                .collect::<ZResult<Vec<_>>>()?;
+            return Ok(Value::Type(ValueType::union(members)));
+        }
+        if let Value::Type(_) = parent {
+            // type arguments are only checked at compile time
+            return Ok(parent);
+        }
+        let index = self.args[0].interpret_expr(val_symt)?;
+        let f = parent.value_ty().namespace().get("_index").cloned().z()?;
+        // an index out of bounds is pointed out where it's written
+        f.into_proc()
+            .z()?
+            .call(vec![parent, index], val_symt)
+            .map_err(|err| {
+                if err.pos.is_empty() {
+                    err.with_span(&self.args[0])
+                } else {
+                    err
+                }
+            })
+    }
+}
+
+impl Reconstruct for Index {
+    fn reconstruct(&self) -> String {
+        format!(
+            "{} [ {} ]",
+            self.parent.reconstruct(),
+            self.args.iter().map(Reconstruct::reconstruct).join(" , ")
+        )
+    }
+}
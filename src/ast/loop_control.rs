@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Ident, Reconstruct},
+    primitives::UNIT_T,
+    types::{
+        position::{GetSpan, Span},
+        r#type::TypeCheckType,
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LoopControl {
+    pub is_continue: bool,
+    pub kwd_span: Option<Span>,
+    pub label: Option<Ident>,
+}
+impl LoopControl {
+    const fn kwd(&self) -> &'static str {
+        if self.is_continue {
+            "continue"
+        } else {
+            "break"
+        }
+    }
+}
+impl GetSpan for LoopControl {
+    fn span(&self) -> Option<Span> {
+        self.kwd_span.merge_span(&self.label)
+    }
+}
+
+impl AstData for LoopControl {
+    fn as_variant(&self) -> Ast {
+        Ast::LoopControl(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking {}", self.kwd());
+        ty_symt.check_loop(
+            self.kwd(),
+            self.label.as_ref().map(|label| &*label.name),
+            &*self,
+        )?;
+        Ok(Arc::clone(&UNIT_T).into())
+    }
+
+    fn interpret_expr(&self, _: &mut InterpretSymTable) -> ZResult<Value> {
+        let label = self.label.as_ref().map(|label| label.name.to_owned());
+        Ok(if self.is_continue {
+            Value::Continue(label)
+        } else {
+            Value::Break(label)
+        })
+    }
+}
+
+impl Reconstruct for LoopControl {
+    fn reconstruct(&self) -> String {
+        if let Some(label) = &self.label {
+            format!("{}'{}", self.kwd(), label.reconstruct())
+        } else {
+            self.kwd().to_owned()
+        }
+    }
+}
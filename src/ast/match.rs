@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, MatchArm, Reconstruct},
+    errors::ZError,
+    primitives::UNIT_T,
+    types::{
+        coverage::{self, Space},
+        position::{GetSpan, Span},
+        r#type::TypeCheckType,
+        sym_table::{InterpretFrameType, TypeCheckFrameType},
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Match {
+    pub kwd_span: Option<Span>,
+    pub subject: Box<Ast>,
+    pub arms: Vec<MatchArm>,
+}
+impl GetSpan for Match {
+    fn span(&self) -> Option<Span> {
+        self.kwd_span
+            .merge_span(&self.subject)
+            .merge_span(&self.arms)
+    }
+}
+
+impl AstData for Match {
+    fn as_variant(&self) -> Ast {
+        Ast::Match(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking match expression");
+        let subject_ty = self.subject.type_check(ty_symt)?;
+        let mut first_ty = None;
+        let mut spaces = vec![];
+        for arm in &mut self.arms {
+            ty_symt.add_frame(TypeCheckFrameType::Normal);
+            let space = if let Some(pattern) = &mut arm.pattern {
+                pattern.type_check(&subject_ty, ty_symt)?
+            } else {
+                Space::Any
+            };
+            let ty = arm.content.block_type(ty_symt, true)?;
+            ty_symt.pop_frame()?;
+            TypeCheckType::check_branch(&mut first_ty, ty, &arm.content)?;
+            if !coverage::is_reachable(&spaces, &space) {
+                ty_symt.warn(ZError::w001().with_span(&*arm));
+            }
+            spaces.push(space);
+        }
+        if let Some(uncovered) = coverage::uncovered(&spaces) {
+            return Err(ZError::t021(uncovered).with_span(&*self));
+        }
+        Ok(first_ty.unwrap_or_else(|| Arc::clone(&UNIT_T).into()))
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring match expression");
+        Ok(Self {
+            kwd_span: self.kwd_span.to_owned(),
+            subject: self.subject.desugared()?.into(),
+            arms: self
+                .arms
+                .iter()
+                .map(|a| {
+                    let mut a = a.to_owned();
+                    a.desugar()?;
+                    Ok(a)
+                })
+                .collect::<ZResult<_>>()?,
+        }
+        .as_variant())
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let subject = self.subject.interpret_expr(val_symt)?;
+        for arm in &self.arms {
+            val_symt.add_frame(InterpretFrameType::Normal);
+            let is_match = if let Some(pattern) = &arm.pattern {
+                pattern.matches(&subject, val_symt)?
+            } else {
+                true
+            };
+            let res = is_match
+                .then(|| arm.content.interpret_block(val_symt, false, true))
+                .transpose();
+            val_symt.pop_frame()?;
+            let res = res?;
+            if let Some(res) = res {
+                return Ok(res);
+            }
+        }
+        Ok(Value::Unit)
+    }
+}
+
+impl Reconstruct for Match {
+    fn reconstruct(&self) -> String {
+        let mut s = format!("match {}", self.subject.reconstruct());
+        for arm in &self.arms {
+            if let Some(pattern) = &arm.pattern {
+                s.push_str(" of ");
+                s.push_str(&pattern.reconstruct());
+            } else {
+                s.push_str(" else");
+            }
+            s.push(' ');
+            s.push_str(&arm.content.reconstruct());
+        }
+        s
+    }
+}
@@ -0,0 +1,31 @@
+use tracing::debug;
+
+use crate::{
+    ast::{AstData, Block, Pattern},
+    errors::{ToZResult, ZResult},
+    types::position::{GetSpan, Span},
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MatchArm {
+    pub kwd_span: Option<Span>,
+    pub pattern: Option<Pattern>,
+    pub content: Block,
+}
+
+impl GetSpan for MatchArm {
+    fn span(&self) -> Option<Span> {
+        self.kwd_span
+            .merge_span(&self.pattern)
+            .merge_span(&self.content)
+    }
+}
+
+impl MatchArm {
+    pub fn desugar(&mut self) -> ZResult<()> {
+        debug!(span = ?self.span(), "Desugaring match arm");
+        self.pattern.as_mut().map(Pattern::desugar).transpose()?;
+        self.content = self.content.desugared()?.as_block().z()?.to_owned();
+        Ok(())
+    }
+}
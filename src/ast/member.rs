@@ -6,9 +6,10 @@ use tracing::debug;
 use crate::{
     ast::{Ast, AstData, Reconstruct},
     errors::{ToZResult, ZError},
+    primitives::{generic_opt, opt_item, ANY_T},
     types::{
         position::{GetSpan, Span},
-        r#type::TypeCheckType,
+        r#type::{Type, TypeCheckType, ValueType},
         token::AccessType,
     },
     InterpretSymTable, TypeCheckSymTable, Value, ZResult,
@@ -30,6 +31,15 @@ impl GetSpan for Member {
     }
 }
 
+impl Member {
+    fn get_field(parent: &Value, name: &str) -> ZResult<Value> {
+        let Value::ClassInstance { attrs, .. } = parent else {
+            return Err(ZError::t005(&parent.ty(), name));
+        };
+        attrs.get(name).cloned().z()
+    }
+}
+
 impl AstData for Member {
     fn as_variant(&self) -> Ast {
         Ast::Member(self.to_owned())
@@ -42,8 +52,31 @@ impl AstData for Member {
     fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
         debug!(span = ?self.span(), "Type-checking member access");
         let parent_type = self.parent.type_check(ty_symt)?;
+        if let (AccessType::Namespace, Some(variant)) = (
+            self.ty,
+            parent_type
+                .as_const()
+                .ok()
+                .and_then(|ty| ty.variant(&self.name)),
+        ) {
+            // a variant with a payload is constructed by calling it, the others are values
+            return Ok(if variant.namespace().contains_key("_new") {
+                TypeCheckType::Const(Arc::clone(variant))
+            } else {
+                Arc::clone(variant).into()
+            });
+        }
         let res = match self.ty {
             AccessType::Method => unreachable!(),
+            // members of a type parameter are only known once the generic proc is called
+            AccessType::Namespace | AccessType::Field
+                if matches!(
+                    &**parent_type.as_const().unwrap_or(&parent_type),
+                    Type::Param(_)
+                ) =>
+            {
+                Arc::clone(&ANY_T)
+            }
             AccessType::Namespace => parent_type
                 .as_const()?
                 .namespace()
@@ -57,6 +90,17 @@ impl AstData for Member {
                 .get(&self.name)
                 .ok_or_else(|| ZError::t005(&parent_type, &self.name).with_span(&*self))
                 .map(Arc::clone)?,
+            AccessType::OptionalField => {
+                let Some(item_ty) = opt_item(&parent_type) else {
+                    return Err(ZError::t022(&parent_type).with_span(&*self));
+                };
+                let field_ty = item_ty
+                    .fields()
+                    .get(&self.name)
+                    .ok_or_else(|| ZError::t005(&item_ty, &self.name).with_span(&*self))
+                    .map(Arc::clone)?;
+                generic_opt(field_ty)
+            }
         };
         Ok(res.into())
     }
@@ -72,14 +116,37 @@ impl AstData for Member {
         let parent = self.parent.interpret_expr(val_symt)?;
         match self.ty {
             AccessType::Method => unreachable!(),
-            AccessType::Field => Ok(todo!()),
-            AccessType::Namespace => Ok(parent
-                .as_type()
-                .z()?
-                .namespace()
-                .get(&self.name)
-                .z()?
-                .to_owned()),
+            AccessType::Field => Self::get_field(&parent, &self.name),
+            AccessType::OptionalField => Ok(Value::Opt(
+                parent
+                    .into_opt()
+                    .z()?
+                    .map(|parent| Self::get_field(&parent, &self.name).map(Box::new))
+                    .transpose()?,
+            )),
+            AccessType::Namespace => {
+                let parent = parent.as_type().z()?;
+                if let Some(variant) = parent.variant(&self.name) {
+                    let ValueType::Type {
+                        namespace, fields, ..
+                    } = &**variant
+                    else {
+                        unreachable!()
+                    };
+                    if namespace.contains_key("_new") {
+                        return Ok(Value::Type(Arc::clone(variant)));
+                    }
+                    // the value of `inst A := value` is kept in the variant's namespace
+                    return Ok(Value::ClassInstance {
+                        ty: Arc::clone(variant),
+                        attrs: fields
+                            .keys()
+                            .map(|k| Ok((k.to_string(), namespace.get(k).z()?.to_owned())))
+                            .collect::<ZResult<_>>()?,
+                    });
+                }
+                Ok(parent.namespace().get(&self.name).z()?.to_owned())
+            }
         }
     }
 }
@@ -91,6 +158,7 @@ impl Reconstruct for Member {
             self.parent.reconstruct(),
             match self.ty {
                 AccessType::Field => ".",
+                AccessType::OptionalField => "?.",
                 AccessType::Method => ":.",
                 AccessType::Namespace => "::",
             },
@@ -8,15 +8,26 @@ mod condition;
 mod declare;
 mod defer;
 mod delete;
+mod r#enum;
+mod r#for;
+mod format_string;
 mod ident;
 mod r#if;
+mod index;
 mod literal;
+mod loop_control;
+mod r#match;
+mod match_arm;
 mod member;
+mod pattern;
 mod preprocess;
 mod procedure;
 mod r#return;
 mod set;
+mod spread;
 mod unary_opr;
+mod unwrap;
+mod r#while;
 
 use std::{fmt::Debug, sync::Arc};
 
@@ -31,15 +42,26 @@ pub use declare::Declare;
 pub use defer::Defer;
 pub use delete::Delete;
 use enum_as_inner::EnumAsInner;
+pub use format_string::FormatString;
 pub use ident::Ident;
+pub use index::Index;
 use itertools::Itertools;
 pub use literal::Literal;
+pub use loop_control::LoopControl;
+pub use match_arm::MatchArm;
+pub use pattern::Pattern;
 pub use preprocess::Preprocess;
-pub use procedure::Procedure;
+pub use procedure::{GenericProc, Procedure};
+pub use r#enum::{Enum, EnumVariant};
+pub use r#for::For;
 pub use r#if::If;
+pub use r#match::Match;
 pub use r#return::Return;
+pub use r#while::While;
 pub use set::Set;
+pub use spread::Spread;
 pub use unary_opr::UnaryOpr;
+pub use unwrap::Unwrap;
 
 pub use crate::ast::member::Member;
 use crate::{
@@ -88,6 +110,15 @@ macro_rules! for_all_variants {
             Ast::Defer(v) => v.$f($($args,)*),
             Ast::Class(v) => v.$f($($args,)*),
             Ast::Member(v) => v.$f($($args,)*),
+            Ast::While(v) => v.$f($($args,)*),
+            Ast::For(v) => v.$f($($args,)*),
+            Ast::LoopControl(v) => v.$f($($args,)*),
+            Ast::Match(v) => v.$f($($args,)*),
+            Ast::Index(v) => v.$f($($args,)*),
+            Ast::Unwrap(v) => v.$f($($args,)*),
+            Ast::FormatString(v) => v.$f($($args,)*),
+            Ast::Enum(v) => v.$f($($args,)*),
+            Ast::Spread(v) => v.$f($($args,)*),
         }
     }
 }
@@ -110,6 +141,15 @@ pub enum Ast {
     Defer(Defer),
     Class(Class),
     Member(Member),
+    While(While),
+    For(For),
+    LoopControl(LoopControl),
+    Match(Match),
+    Index(Index),
+    Unwrap(Unwrap),
+    FormatString(FormatString),
+    Enum(Enum),
+    Spread(Spread),
 }
 impl GetSpan for Ast {
     fn span(&self) -> Option<Span> {
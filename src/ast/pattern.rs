@@ -0,0 +1,267 @@
+use std::{collections::HashMap, sync::Arc};
+
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Ident, Literal, Member, Reconstruct},
+    errors::ZError,
+    types::{
+        coverage::{Ctor, Space},
+        position::{GetSpan, Span},
+        r#type::{Type, TypeCheckType},
+        token::AccessType,
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Pattern {
+    Wildcard(Option<Span>),
+    Binding(Ident),
+    Value(Box<Ast>),
+    Type {
+        binding: Option<Ident>,
+        kwd_span: Option<Span>,
+        ty: Box<Ast>,
+    },
+    Struct {
+        ty: Box<Ast>,
+        paren_spans: Option<(Span, Span)>,
+        fields: Vec<(Ident, Self)>,
+    },
+}
+impl GetSpan for Pattern {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Wildcard(span) => span.to_owned(),
+            Self::Binding(ident) => ident.span(),
+            Self::Value(value) => value.span(),
+            Self::Type {
+                binding,
+                kwd_span,
+                ty,
+            } => binding.merge_span(kwd_span).merge_span(ty),
+            Self::Struct {
+                ty, paren_spans, ..
+            } => ty.merge_span(paren_spans.as_ref().map(|a| &a.1)),
+        }
+    }
+}
+
+impl Pattern {
+    /// Type-checks the pattern, returning the space of values it matches for coverage analysis
+    pub fn type_check(
+        &mut self,
+        subject_ty: &TypeCheckType,
+        ty_symt: &mut TypeCheckSymTable,
+    ) -> ZResult<Space> {
+        debug!(span = ?self.span(), "Type-checking pattern");
+        let subject_is_any = ***subject_ty == Type::Any;
+        Ok(match self {
+            Self::Wildcard(_) => Space::Any,
+            Self::Binding(ident) => {
+                ty_symt.declare_val(&ident.name, subject_ty.to_owned())?;
+                Space::Any
+            }
+            Self::Value(value) => {
+                let value_ty = value.type_check(ty_symt)?;
+                if !value_ty.is_assignable_to(subject_ty) {
+                    return Err(ZError::t011(subject_ty, &value_ty).with_span(&**value));
+                }
+                match &**value {
+                    Ast::Literal(Literal {
+                        content: Value::Bool(b),
+                        ..
+                    }) => Space::Ctor(Ctor::Bool(*b), vec![]),
+                    Ast::Literal(Literal { content, .. }) => {
+                        Space::Ctor(Ctor::Literal(content.to_owned().into()), vec![])
+                    }
+                    // a variant without a payload has only the one value
+                    Ast::Member(Member {
+                        ty: AccessType::Namespace,
+                        name,
+                        ..
+                    }) if subject_ty
+                        .variant(name)
+                        .is_some_and(|variant| variant.same_as(&value_ty)) =>
+                    {
+                        Space::Ctor(
+                            Ctor::Member {
+                                ty: Arc::clone(&value_ty),
+                                union: Arc::clone(subject_ty),
+                            },
+                            vec![],
+                        )
+                    }
+                    _ => Space::Opaque,
+                }
+            }
+            Self::Type { binding, ty, .. } => {
+                let mut ty = Arc::clone(ty.type_check(ty_symt)?.as_const()?);
+                let space = if subject_is_any {
+                    Space::Opaque
+                } else if let Some(members) = subject_ty.members() {
+                    ty = subject_ty.narrow(&ty, true);
+                    match &*ty {
+                        _ if ty.same_as(subject_ty) => Space::Any,
+                        Type::Union(tys) if tys.is_empty() => Space::Empty,
+                        Type::Union(_) => Space::Opaque,
+                        _ if members.iter().any(|member| member.same_as(&ty)) => Space::Ctor(
+                            Ctor::Member {
+                                ty: Arc::clone(&ty),
+                                union: Arc::clone(subject_ty),
+                            },
+                            vec![],
+                        ),
+                        _ => Space::Opaque,
+                    }
+                } else if ty.same_as(subject_ty) {
+                    Space::Any
+                } else {
+                    Space::Empty
+                };
+                if let Some(binding) = binding {
+                    ty_symt.declare_val(&binding.name, ty.into())?;
+                }
+                space
+            }
+            Self::Struct { ty, fields, .. } => {
+                let ty = Arc::clone(ty.type_check(ty_symt)?.as_const()?);
+                let field_tys = ty.fields();
+                let mut field_spaces = HashMap::new();
+                for (name, pattern) in fields {
+                    let Some(field_ty) = field_tys.get(&name.name) else {
+                        return Err(ZError::t005(&ty, &name.name).with_span(&*name));
+                    };
+                    let space = pattern.type_check(&Arc::clone(field_ty).into(), ty_symt)?;
+                    field_spaces.insert(name.name.to_owned(), space);
+                }
+                if subject_is_any {
+                    Space::Opaque
+                } else if ty.same_as(subject_ty) {
+                    let field_names = field_tys.keys().cloned().sorted().collect_vec();
+                    let args = field_names
+                        .iter()
+                        .map(|name| field_spaces.remove(name).unwrap_or(Space::Any))
+                        .collect();
+                    Space::Ctor(
+                        Ctor::Struct {
+                            ty: Arc::clone(&ty),
+                            fields: field_names,
+                        },
+                        args,
+                    )
+                } else if subject_ty
+                    .members()
+                    .is_some_and(|members| members.iter().any(|member| member.same_as(&ty)))
+                {
+                    // the variant of an enum, which is only fully matched if its fields are
+                    if field_spaces
+                        .values()
+                        .all(|space| matches!(space, Space::Any))
+                    {
+                        Space::Ctor(
+                            Ctor::Member {
+                                ty: Arc::clone(&ty),
+                                union: Arc::clone(subject_ty),
+                            },
+                            vec![],
+                        )
+                    } else {
+                        Space::Opaque
+                    }
+                } else {
+                    Space::Empty
+                }
+            }
+        })
+    }
+
+    pub fn desugar(&mut self) -> ZResult<()> {
+        debug!(span = ?self.span(), "Desugaring pattern");
+        match self {
+            Self::Wildcard(_) | Self::Binding(_) => (),
+            Self::Value(value) => value.desugar()?,
+            Self::Type { ty, .. } => ty.desugar()?,
+            Self::Struct { ty, fields, .. } => {
+                ty.desugar()?;
+                for (_, pattern) in fields {
+                    pattern.desugar()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Tests `value` against the pattern, declaring any bindings in the current frame
+    pub fn matches(&self, value: &Value, val_symt: &mut InterpretSymTable) -> ZResult<bool> {
+        Ok(match self {
+            Self::Wildcard(_) => true,
+            Self::Binding(ident) => {
+                val_symt.declare_val(&ident.name, value.to_owned());
+                true
+            }
+            Self::Value(pattern) => pattern.interpret_expr(val_symt)? == *value,
+            Self::Type { binding, ty, .. } => {
+                let Value::Type(ty) = ty.interpret_expr(val_symt)? else {
+                    unreachable!()
+                };
+                let is_match = value.value_ty().is_subtype_of(&ty);
+                if let (true, Some(binding)) = (is_match, binding) {
+                    val_symt.declare_val(&binding.name, value.to_owned());
+                }
+                is_match
+            }
+            Self::Struct { ty, fields, .. } => {
+                let Value::Type(ty) = ty.interpret_expr(val_symt)? else {
+                    unreachable!()
+                };
+                let Value::ClassInstance { ty: value_ty, attrs } = value else {
+                    return Ok(false);
+                };
+                if !value_ty.is_subtype_of(&ty) {
+                    return Ok(false);
+                }
+                for (name, pattern) in fields {
+                    let Some(attr) = attrs.get(&*name.name) else {
+                        return Ok(false);
+                    };
+                    if !pattern.matches(attr, val_symt)? {
+                        return Ok(false);
+                    }
+                }
+                true
+            }
+        })
+    }
+}
+
+impl Reconstruct for Pattern {
+    fn reconstruct(&self) -> String {
+        match self {
+            Self::Wildcard(_) => "_".into(),
+            Self::Binding(ident) => ident.reconstruct(),
+            Self::Value(value) => value.reconstruct(),
+            Self::Type { binding, ty, .. } => {
+                if let Some(binding) = binding {
+                    format!("{} istype {}", binding.reconstruct(), ty.reconstruct())
+                } else {
+                    format!("istype {}", ty.reconstruct())
+                }
+            }
+            Self::Struct { ty, fields, .. } => format!(
+                "{}({})",
+                ty.reconstruct(),
+                fields
+                    .iter()
+                    .map(|(name, pattern)| format!(
+                        "{}: {}",
+                        name.reconstruct(),
+                        pattern.reconstruct()
+                    ))
+                    .join(", ")
+            ),
+        }
+    }
+}
@@ -1,38 +1,120 @@
-use std::sync::Arc;
+use std::{mem, sync::Arc};
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
+use smol_str::SmolStr;
 use tracing::debug;
 
 use crate::{
-    ast::{argument::Argument, Ast, AstData, Block, Reconstruct},
+    ast::{argument::Argument, Ast, AstData, Block, Ident, Reconstruct},
     errors::{ToZResult, ZError},
-    primitives::generic_proc,
+    primitives::{generic_proc, ANY_T},
     types::{
         position::{GetSpan, Span},
-        r#type::TypeCheckType,
+        r#type::{Type, TypeArg, TypeCheckType},
         sym_table::TypeCheckFrameType,
         value::Proc,
     },
     InterpretSymTable, TypeCheckSymTable, Value, ZResult,
 };
 
+/// A generic proc before it was type-checked, with the symbol table that it was declared in, so
+/// that its body can be type-checked again with the type arguments of each call
+#[derive(Clone, Debug)]
+pub struct GenericProc {
+    /// The type of the proc, which is its own so that calls can find its body by it
+    pub ty: Arc<Type>,
+    pub procedure: Procedure,
+    pub ty_symt: Arc<TypeCheckSymTable>,
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct Procedure {
     pub is_fn: bool,
     pub kwd_span: Option<Span>,
+    pub label: Option<Ident>,
+    pub type_params: Vec<Ident>,
     pub args: Vec<Argument>,
+    /// Whether the first argument is `&$`, so that changes to the receiver are kept
+    pub updates_receiver: bool,
     pub return_type: Option<Box<Ast>>,
     pub content: Block,
 }
 impl GetSpan for Procedure {
     fn span(&self) -> Option<Span> {
         self.kwd_span
+            .merge_span(&self.type_params)
+            .merge_span(&self.label)
             .merge_span(&self.args)
             .merge_span(&self.return_type)
             .merge_span(&self.content)
     }
 }
 
+impl Procedure {
+    fn declare_type_params(&self, ty_symt: &mut TypeCheckSymTable) -> ZResult<()> {
+        for param in &self.type_params {
+            ty_symt.declare_val(
+                &param.name,
+                TypeCheckType::Const(Arc::new(Type::Param(param.name.to_owned()))),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// The type of the procedure from its signature alone, returning `_any` if no return type is
+    /// given, for a method that is called before it is type-checked
+    pub fn declared_ty(&self, ty_symt: &mut TypeCheckSymTable) -> ZResult<Arc<Type>> {
+        let mut new_self = self.to_owned();
+        ty_symt.add_frame(TypeCheckFrameType::Normal);
+        new_self.declare_type_params(ty_symt)?;
+        let arg_tys = new_self
+            .args
+            .iter_mut()
+            .map(|arg| arg.type_check(ty_symt))
+            .collect::<ZResult<Vec<_>>>()?;
+        let ret_ty = if let Some(ty) = &mut new_self.return_type {
+            Arc::clone(ty.type_check(ty_symt)?.as_const()?)
+        } else {
+            Arc::clone(&ANY_T)
+        };
+        ty_symt.pop_frame()?;
+        Ok(generic_proc(arg_tys, ret_ty))
+    }
+
+    /// Type-checks the body of the generic proc of type `ty` with the type arguments of a call,
+    /// as the members of its type parameters are unknown until then.
+    /// The body is checked in the symbol table that the proc was declared in, which only knows of
+    /// generic procs declared before it, so this cannot recurse forever.
+    pub fn instantiate(
+        ty: &Arc<Type>,
+        type_args: &[(SmolStr, TypeArg)],
+        ty_symt: &TypeCheckSymTable,
+    ) -> ZResult<()> {
+        let Some(generic) = ty_symt
+            .2
+            .iter()
+            .find(|generic| Arc::ptr_eq(&generic.ty, ty))
+        else {
+            return Ok(());
+        };
+        let mut procedure = generic.procedure.to_owned();
+        let mut ty_symt = (*generic.ty_symt).to_owned();
+        ty_symt.add_frame(TypeCheckFrameType::Normal);
+        for param in mem::take(&mut procedure.type_params) {
+            // a type parameter that is not inferred stays a parameter
+            let arg = type_args
+                .iter()
+                .find_map(|(k, v)| match v {
+                    Either::Right(Either::Right(arg)) if *k == param.name => Some(Arc::clone(arg)),
+                    _ => None,
+                })
+                .unwrap_or_else(|| Arc::new(Type::Param(param.name.to_owned())));
+            ty_symt.declare_val(&param.name, TypeCheckType::Const(arg))?;
+        }
+        procedure.type_check(&mut ty_symt).map(|_| ())
+    }
+}
+
 impl AstData for Procedure {
     fn as_variant(&self) -> Ast {
         Ast::Procedure(self.to_owned())
@@ -40,16 +122,22 @@ impl AstData for Procedure {
 
     fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
         debug!(span = ?self.span(), "Type-checking procedure statement");
+        let generic =
+            (!self.type_params.is_empty()).then(|| (self.to_owned(), Arc::new(ty_symt.to_owned())));
+        let frame_ty: fn(_) -> _ = if self.is_fn {
+            TypeCheckFrameType::Function
+        } else {
+            TypeCheckFrameType::NormalReturnable
+        };
+        ty_symt.add_frame(frame_ty(None)).label =
+            self.label.as_ref().map(|label| label.name.to_owned());
+        self.declare_type_params(ty_symt)?;
         let sig_ret_ty = if let Some(ty) = &mut self.return_type {
             Some(Arc::clone(ty.type_check(ty_symt)?.as_const()?))
         } else {
             None
         };
-        ty_symt.add_frame(if self.is_fn {
-            TypeCheckFrameType::Function
-        } else {
-            TypeCheckFrameType::NormalReturnable
-        }(sig_ret_ty.map(|a| Arc::clone(&a))));
+        ty_symt.front_mut()?.ty = frame_ty(sig_ret_ty);
         let arg_tys = self
             .args
             .iter_mut()
@@ -64,7 +152,7 @@ impl AstData for Procedure {
             unreachable!()
         };
         let ret_ty = Arc::clone(if let Some(ret_ty) = ret_ty {
-            if !Arc::ptr_eq(&res, ret_ty) {
+            if !res.is_assignable_to(ret_ty) {
                 return Err(ZError::t009(ret_ty, &res));
             }
             ret_ty
@@ -72,7 +160,17 @@ impl AstData for Procedure {
             &res
         });
         ty_symt.pop_frame()?;
-        Ok(generic_proc(arg_tys, ret_ty).into())
+        let ty = generic_proc(arg_tys, ret_ty);
+        if let Some((procedure, generic_symt)) = generic {
+            let ty = Arc::new((*ty).to_owned());
+            ty_symt.2.push(GenericProc {
+                ty: Arc::clone(&ty),
+                procedure,
+                ty_symt: generic_symt,
+            });
+            return Ok(ty.into());
+        }
+        Ok(ty.into())
     }
 
     fn desugared(&self) -> ZResult<Ast> {
@@ -87,6 +185,11 @@ impl AstData for Procedure {
                 Ok(a)
             })
             .collect::<Result<Vec<_>, _>>()?;
+        new_self.return_type = self
+            .return_type
+            .as_ref()
+            .map(|ty| ty.desugared().map(Box::new))
+            .transpose()?;
         new_self.content = self.content.desugared()?.as_block().z()?.to_owned();
         Ok(new_self.as_variant())
     }
@@ -94,8 +197,10 @@ impl AstData for Procedure {
     fn interpret_expr(&self, _val_symt: &mut InterpretSymTable) -> ZResult<Value> {
         Ok(Value::Proc(Proc::Defined {
             is_fn: self.is_fn,
+            label: self.label.as_ref().map(|label| label.name.to_owned()),
             content: self.content.to_owned(),
             args: self.args.iter().map(|a| a.name.name.to_owned()).collect(),
+            updates_receiver: self.updates_receiver,
         }))
     }
 }
@@ -103,6 +208,15 @@ impl Reconstruct for Procedure {
     fn reconstruct(&self) -> String {
         let mut s = String::new();
         s.push_str(if self.is_fn { "fn" } else { "proc" });
+        if let Some(label) = &self.label {
+            s.push('\'');
+            s.push_str(&label.reconstruct());
+        }
+        if !self.type_params.is_empty() {
+            s.push('[');
+            s.push_str(&self.type_params.iter().map(Reconstruct::reconstruct).join(", "));
+            s.push(']');
+        }
         if !self.args.is_empty() {
             s.push('|');
             s.push_str(&self.args.iter().map(Reconstruct::reconstruct).join(", "));
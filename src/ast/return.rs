@@ -3,7 +3,7 @@ use std::sync::Arc;
 use tracing::debug;
 
 use crate::{
-    ast::{Ast, AstData, Reconstruct},
+    ast::{Ast, AstData, Ident, Reconstruct},
     primitives::UNIT_T,
     types::{
         position::{GetSpan, Span},
@@ -15,11 +15,14 @@ use crate::{
 #[derive(Clone, PartialEq, Debug)]
 pub struct Return {
     pub kwd_span: Option<Span>,
+    pub label: Option<Ident>,
     pub value: Box<Ast>,
 }
 impl GetSpan for Return {
     fn span(&self) -> Option<Span> {
-        self.kwd_span.merge_span(&self.value)
+        self.kwd_span
+            .merge_span(&self.label)
+            .merge_span(&self.value)
     }
 }
 
@@ -30,7 +33,11 @@ impl AstData for Return {
 
     fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
         let value_ty = &self.value.type_check(ty_symt)?;
-        ty_symt.set_block_return(Arc::clone(value_ty), self.value.span())?;
+        ty_symt.set_block_return(
+            Arc::clone(value_ty),
+            self.label.as_ref().map(|label| &*label.name),
+            self.value.span(),
+        )?;
         Ok(Arc::clone(&UNIT_T).into())
     }
 
@@ -38,20 +45,26 @@ impl AstData for Return {
         debug!(span = ?self.span(), "Desugaring return statement");
         Ok(Self {
             kwd_span: self.kwd_span.to_owned(),
+            label: self.label.to_owned(),
             value: self.value.desugared()?.into(),
         }
         .as_variant())
     }
 
     fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
-        Ok(Value::Return(Box::new(
-            self.value.interpret_expr(val_symt)?,
-        )))
+        Ok(Value::Return(
+            Box::new(self.value.interpret_expr(val_symt)?),
+            self.label.as_ref().map(|label| label.name.to_owned()),
+        ))
     }
 }
 
 impl Reconstruct for Return {
     fn reconstruct(&self) -> String {
-        format!("ret {}", self.value.reconstruct())
+        if let Some(label) = &self.label {
+            format!("ret'{} {}", label.reconstruct(), self.value.reconstruct())
+        } else {
+            format!("ret {}", self.value.reconstruct())
+        }
     }
 }
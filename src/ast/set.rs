@@ -1,10 +1,11 @@
 use tracing::debug;
 
 use crate::{
-    ast::{Ast, AstData, Reconstruct},
+    ast::{Ast, AstData, Ident, Member, Reconstruct},
     types::{
         position::{GetSpan, Span},
         r#type::TypeCheckType,
+        token::AccessType,
     },
     InterpretSymTable, TypeCheckSymTable, Value, ZError, ZResult,
 };
@@ -34,13 +35,28 @@ impl AstData for Set {
             return Err(ZError::t006().with_span(&*self.variable));
         }
         let content_type = self.content.type_check(ty_symt)?;
+        if let Ast::Member(Member {
+            ty: AccessType::Field,
+            ..
+        }) = &*self.variable
+        {
+            let field_type = self.variable.type_check(ty_symt)?;
+            if !content_type.is_assignable_to(&field_type) {
+                return Err(ZError::t011(&field_type, &content_type).with_span(&*self));
+            }
+            // the instance holding the field is itself set
+            let root = Self::root_ident(&self.variable)?;
+            let root_type = ty_symt.get_val(&root.name, root)?;
+            ty_symt.set_val(&root.name, &root_type, root)?;
+            return Ok(field_type);
+        }
         let name = if let Ast::Ident(ident) = &*self.variable {
             &ident.name
         } else {
             return Err(ZError::t008().with_span(&self.variable));
         };
         let var_type = ty_symt.get_val(name, self.variable.span())?;
-        ty_symt.set_val(name, content_type, self.variable.span())?;
+        ty_symt.set_val(name, &content_type, self.variable.span())?;
 
         Ok(var_type)
     }
@@ -55,16 +71,51 @@ impl AstData for Set {
 
     fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
         let var = self.content.interpret_expr(val_symt)?;
-        let name = if let Ast::Ident(ident) = &*self.variable {
-            &ident.name
-        } else {
-            return Err(ZError::t008().with_span(&self.variable));
-        };
-        val_symt.set_val(name, var.to_owned(), self)?;
+        Self::set(&self.variable, var.to_owned(), val_symt)?;
         Ok(var)
     }
 }
 
+impl Set {
+    fn root_ident(variable: &Ast) -> ZResult<&Ident> {
+        match variable {
+            Ast::Ident(ident) => Ok(ident),
+            Ast::Member(Member {
+                ty: AccessType::Field,
+                parent,
+                ..
+            }) => Self::root_ident(parent),
+            _ => Err(ZError::t008().with_span(variable)),
+        }
+    }
+
+    /// Sets `variable` to `value`.
+    /// For `a.b.c = value`, `a.b` and `a` are also set to their updated instances
+    pub(crate) fn set(
+        variable: &Ast,
+        value: Value,
+        val_symt: &mut InterpretSymTable,
+    ) -> ZResult<()> {
+        match variable {
+            Ast::Ident(ident) => val_symt.set_val(&ident.name, value, variable),
+            Ast::Member(Member {
+                ty: AccessType::Field,
+                name,
+                parent,
+                ..
+            }) => {
+                let Value::ClassInstance { ty, mut attrs } = parent.interpret_expr(val_symt)?
+                else {
+                    unreachable!()
+                };
+                attrs.insert(name.to_string(), value);
+                Self::set(parent, Value::ClassInstance { ty, attrs }, val_symt)
+            }
+            _ => Err(ZError::t008().with_span(variable)),
+        }
+    }
+}
+
 impl Reconstruct for Set {
     fn reconstruct(&self) -> String {
         format!(
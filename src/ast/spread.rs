@@ -0,0 +1,52 @@
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Reconstruct},
+    errors::ZError,
+    types::{
+        position::{GetSpan, Span},
+        r#type::TypeCheckType,
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+/// `..x`, which in a class body inherits from the class `x`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Spread {
+    pub dots_span: Option<Span>,
+    pub content: Box<Ast>,
+}
+impl GetSpan for Spread {
+    fn span(&self) -> Option<Span> {
+        self.dots_span.merge_span(&self.content)
+    }
+}
+
+impl AstData for Spread {
+    fn as_variant(&self) -> Ast {
+        Ast::Spread(self.to_owned())
+    }
+
+    fn type_check(&mut self, _ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        // classes handle their own spreads, anywhere else is unsupported for now
+        Err(ZError::t034().with_span(&*self))
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring spread");
+        Ok(Ast::Spread(Self {
+            dots_span: self.dots_span.to_owned(),
+            content: Box::new(self.content.desugared()?),
+        }))
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        self.content.interpret_expr(val_symt)
+    }
+}
+
+impl Reconstruct for Spread {
+    fn reconstruct(&self) -> String {
+        format!(".. {}", self.content.reconstruct())
+    }
+}
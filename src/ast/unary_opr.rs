@@ -1,16 +1,17 @@
-use std::collections::HashMap;
-
 use tracing::debug;
 
 use crate::{
-    ast::{Ast, AstData, Call, Member, Reconstruct},
+    ast::{Ast, AstData, BinaryOpr, Block, Call, Declare, Ident, Member, Reconstruct, Set},
     types::{
         position::{GetSpan, Span},
         token::{AccessType, OprType},
     },
-    ZResult,
+    Value, ZError, ZResult,
 };
 
+// the `'` keeps it from clashing with idents in code
+const OLD_VAR: &str = "postfix'old";
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct UnaryOpr {
     pub ty: OprType,
@@ -30,14 +31,63 @@ impl AstData for UnaryOpr {
 
     fn desugared(&self) -> ZResult<Ast> {
         debug!(span = ?self.span(), "Desugaring unary operator");
-        Ok(Call {
+        if let OprType::Inc | OprType::Dec = self.ty {
+            // `x++` is `{ old := x; x = x + 1 @ (x @ type); old }`, so it gives the value before
+            let old_var = Ident {
+                name: OLD_VAR.into(),
+                name_span: self.operand.span(),
+            };
+            let one = BinaryOpr {
+                ty: OprType::TypeCast,
+                opr_span: None,
+                operand1: Box::new(Value::I32(1).as_ast()),
+                operand2: Box::new(Ast::BinaryOpr(BinaryOpr {
+                    ty: OprType::TypeCast,
+                    opr_span: None,
+                    operand1: self.operand.to_owned(),
+                    operand2: Box::new(Ast::Ident(Ident::new("type"))),
+                })),
+            };
+            return Block {
+                brace_spans: None,
+                content: vec![
+                    Declare {
+                        variable: old_var.as_variant().into(),
+                        content: self.operand.to_owned(),
+                        flags: vec![],
+                        ty: None,
+                        eq_span: None,
+                    }
+                    .as_variant(),
+                    Set {
+                        variable: self.operand.to_owned(),
+                        eq_span: self.opr_span.to_owned(),
+                        content: Box::new(Ast::BinaryOpr(BinaryOpr {
+                            ty: if self.ty == OprType::Inc {
+                                OprType::Add
+                            } else {
+                                OprType::Sub
+                            },
+                            opr_span: None,
+                            operand1: self.operand.to_owned(),
+                            operand2: Box::new(one.as_variant()),
+                        })),
+                    }
+                    .as_variant(),
+                    old_var.as_variant(),
+                ],
+            }
+            .desugared();
+        }
+        Call {
             called: Member {
                 ty: AccessType::Method,
                 name: match self.ty {
                     OprType::Not => "_not",
-                    OprType::UnPlus => "_un_plus",
-                    OprType::UnMinus => "_un_minus",
-                    _ => panic!(),
+                    OprType::UnPlus => "_un_add",
+                    OprType::UnMinus => "_un_sub",
+                    OprType::BitCompl => "compl",
+                    _ => return Err(ZError::t031(self.ty).with_span(self)),
                 }
                 .into(),
                 name_span: None,
@@ -48,9 +98,9 @@ impl AstData for UnaryOpr {
             .into(),
             paren_spans: None,
             args: vec![],
-            kwargs: HashMap::default(),
+            kwargs: vec![],
         }
-        .as_variant())
+        .desugared()
     }
 }
 
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Reconstruct},
+    errors::{ToZResult, ZError},
+    primitives::res_args,
+    types::{
+        position::{GetSpan, Span},
+        r#type::{Type, TypeCheckType},
+        sym_table::TypeCheckFrameType,
+        value::Proc,
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+/// `x!!`, or `x?` / `x!?` if `propagate` is set
+#[derive(Clone, PartialEq, Debug)]
+pub struct Unwrap {
+    pub operand: Box<Ast>,
+    pub opr_span: Option<Span>,
+    pub propagate: bool,
+}
+impl GetSpan for Unwrap {
+    fn span(&self) -> Option<Span> {
+        self.operand.merge_span(&self.opr_span)
+    }
+}
+
+impl AstData for Unwrap {
+    fn as_variant(&self) -> Ast {
+        Ast::Unwrap(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking unwrap");
+        let operand_ty = self.operand.type_check(ty_symt)?;
+        let Some((_, ty)) = operand_ty
+            .namespace()
+            .get("_unwrap")
+            .and_then(|f| f.proc_sig())
+        else {
+            return Err(ZError::t005(&operand_ty, "_unwrap").with_span(&*self));
+        };
+        if self.propagate {
+            // the enclosing proc returns the operand if it fails to unwrap
+            let ret_ty = ty_symt.0.iter().find_map(|frame| match &frame.ty {
+                TypeCheckFrameType::Function(ret_ty)
+                | TypeCheckFrameType::NormalReturnable(ret_ty) => Some(ret_ty.to_owned()),
+                _ => None,
+            });
+            let ret_ty = ret_ty
+                .flatten()
+                .filter(|ret_ty| match (&**ret_ty, &**operand_ty) {
+                    (Type::Generic { base: ret_base, .. }, Type::Generic { base, .. }) => {
+                        Arc::ptr_eq(ret_base, base)
+                    }
+                    _ => false,
+                })
+                .unwrap_or_else(|| Arc::clone(&operand_ty));
+            // an error can only be propagated into a `res` with the same error type
+            if let (Some((_, ret_err_ty)), Some((_, err_ty))) =
+                (res_args(&ret_ty), res_args(&operand_ty))
+            {
+                if !err_ty.is_assignable_to(&ret_err_ty) {
+                    return Err(ZError::t009(&ret_ty, &operand_ty).with_span(&*self));
+                }
+            }
+            ty_symt.set_block_return(ret_ty, None, &*self)?;
+        }
+        Ok(ty.into())
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring unwrap");
+        Ok(Self {
+            operand: self.operand.desugared()?.into(),
+            opr_span: self.opr_span.to_owned(),
+            propagate: self.propagate,
+        }
+        .as_variant())
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let operand = self.operand.interpret_expr(val_symt)?;
+        let unwrap = operand
+            .value_ty()
+            .namespace()
+            .get("_unwrap")
+            .cloned()
+            .z()?
+            .into_proc()
+            .z()?;
+        // only a failing builtin `_unwrap` means that the operand is empty; errors raised inside a
+        // defined `_unwrap` are passed on as they are
+        let is_builtin = matches!(unwrap, Proc::Builtin { .. });
+        match unwrap.call(vec![operand.to_owned()], val_symt) {
+            // `?` returns from the enclosing procedure, through whatever expression it is in
+            Err(_) if is_builtin && self.propagate => Err(ZError::i000(operand, None)),
+            Err(err) if is_builtin => Err(err.with_span(self)),
+            res => res,
+        }
+    }
+}
+
+impl Reconstruct for Unwrap {
+    fn reconstruct(&self) -> String {
+        format!(
+            "{} {}",
+            self.operand.reconstruct(),
+            if self.propagate { "?" } else { "!!" }
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use smol_str::SmolStr;
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, AstData, Block, Ident, Reconstruct},
+    errors::{ToZResult, ZError},
+    primitives::{BOOL_T, UNIT_T},
+    types::{
+        position::{GetSpan, Span},
+        r#type::TypeCheckType,
+        sym_table::{InterpretFrameType, TypeCheckFrameType},
+    },
+    InterpretSymTable, TypeCheckSymTable, Value, ZResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct While {
+    pub is_do_while: bool,
+    pub label: Option<Ident>,
+    pub kwd_span: Option<Span>,
+    pub do_kwd_span: Option<Span>,
+    pub condition: Box<Ast>,
+    pub content: Block,
+    /// Run once before the loop, in a frame around it (used by desugared `for` loops)
+    pub init: Option<Box<Ast>>,
+}
+impl While {
+    fn interpret_loop(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let mut skip_condition = self.is_do_while;
+        loop {
+            if !skip_condition && self.condition.interpret_expr(val_symt)? != Value::Bool(true) {
+                break;
+            }
+            skip_condition = false;
+            match self.content.interpret_block(val_symt, false, true)? {
+                Value::Break(label) if self.is_own_label(&label) => break,
+                Value::Continue(label) if self.is_own_label(&label) => (),
+                res @ (Value::Break(_) | Value::Continue(_)) => return Ok(res),
+                _ => (),
+            }
+        }
+        Ok(Value::Unit)
+    }
+    fn is_own_label(&self, label: &Option<SmolStr>) -> bool {
+        label.is_none() || self.label.as_ref().map(|label| &label.name) == label.as_ref()
+    }
+}
+impl GetSpan for While {
+    fn span(&self) -> Option<Span> {
+        self.do_kwd_span
+            .merge_span(&self.kwd_span)
+            .merge_span(&self.label)
+            .merge_span(&self.condition)
+            .merge_span(&self.content)
+    }
+}
+
+impl AstData for While {
+    fn as_variant(&self) -> Ast {
+        Ast::While(self.to_owned())
+    }
+
+    fn type_check(&mut self, ty_symt: &mut TypeCheckSymTable) -> ZResult<TypeCheckType> {
+        debug!(span = ?self.span(), "Type-checking while loop");
+        if let Some(init) = &mut self.init {
+            ty_symt.add_frame(TypeCheckFrameType::Normal);
+            init.type_check(ty_symt)?;
+        }
+        let condition_ty = self.condition.type_check(ty_symt)?;
+        if !condition_ty.is_assignable_to(&BOOL_T) {
+            return Err(ZError::t011(&BOOL_T, &condition_ty).with_span(&*self.condition));
+        }
+        ty_symt.add_frame(TypeCheckFrameType::Loop).label =
+            self.label.as_ref().map(|label| label.name.to_owned());
+        self.content.block_type(ty_symt, true)?;
+        ty_symt.pop_frame()?;
+        if self.init.is_some() {
+            ty_symt.pop_frame()?;
+        }
+        Ok(Arc::clone(&UNIT_T).into())
+    }
+
+    fn desugared(&self) -> ZResult<Ast> {
+        debug!(span = ?self.span(), "Desugaring while loop");
+        let mut new_self = self.to_owned();
+        new_self.condition = self.condition.desugared()?.into();
+        new_self.content = self.content.desugared()?.as_block().z()?.to_owned();
+        new_self.init = self
+            .init
+            .as_ref()
+            .map(|init| init.desugared().map(Box::new))
+            .transpose()?;
+        Ok(new_self.as_variant())
+    }
+
+    fn interpret_expr(&self, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+        let Some(init) = &self.init else {
+            return self.interpret_loop(val_symt);
+        };
+        val_symt.add_frame(InterpretFrameType::Normal);
+        let res = init
+            .interpret_expr(val_symt)
+            .and_then(|_| self.interpret_loop(val_symt));
+        val_symt.pop_frame()?;
+        res
+    }
+}
+
+impl Reconstruct for While {
+    fn reconstruct(&self) -> String {
+        let label = self
+            .label
+            .as_ref()
+            .map_or_else(String::new, |label| format!("'{}", label.reconstruct()));
+        if self.is_do_while {
+            format!(
+                "do{label} {} while {}",
+                self.content.reconstruct(),
+                self.condition.reconstruct()
+            )
+        } else {
+            format!(
+                "while{label} {} {}",
+                self.condition.reconstruct(),
+                self.content.reconstruct()
+            )
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use itertools::Itertools;
+use smol_str::SmolStr;
 
-use crate::{errors::ZError, types::value::Value};
+use crate::{
+    ast::{Ast, Reconstruct},
+    errors::ZError,
+    types::value::Value,
+};
 
 impl ZError {
     #[must_use]
@@ -13,4 +18,49 @@ impl ZError {
             ),
         )
     }
+    #[must_use]
+    pub fn i000(value: Value, label: Option<SmolStr>) -> Self {
+        let mut err = Self::new(
+            "I000",
+            format!("`{value}` was propagated outside of a procedure"),
+        );
+        err.propagated = Some(Box::new(value));
+        err.propagated_label = label;
+        err
+    }
+    #[must_use]
+    pub fn i002(args: &[Value]) -> Self {
+        Self::new("I002", format!("`!!` assertion failed on `{}`", args[0]))
+    }
+    #[must_use]
+    pub fn i003(num: &Value) -> Self {
+        Self::new(
+            "I003",
+            format!("`frac` with numerator `{num}` has a zero denominator"),
+        )
+    }
+    #[must_use]
+    pub fn i004(args: &[Value]) -> Self {
+        Self::new(
+            "I004",
+            format!("Cannot shift `{}` by `{}` bits", args[0], args[1]),
+        )
+    }
+    #[must_use]
+    pub fn i005(receiver: &Ast) -> Self {
+        Self::new(
+            "I005",
+            format!(
+                "`{}` cannot be updated in place, as it is an item of a collection",
+                receiver.reconstruct()
+            ),
+        )
+    }
+    #[must_use]
+    pub fn i006(args: &[Value]) -> Self {
+        Self::new(
+            "I006",
+            format!("Index `{}` is out of bounds for `{}`", args[1], args[0]),
+        )
+    }
 }
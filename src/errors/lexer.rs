@@ -12,4 +12,30 @@ impl ZError {
     pub fn l002() -> Self {
         Self::new("L002", "Unexpected end of comment".to_owned())
     }
+    #[must_use]
+    pub fn l003(raw: &str) -> Self {
+        Self::new("L003", format!("Invalid number literal `{raw}`"))
+    }
+    #[must_use]
+    pub fn l004(raw: &str, ty: &str) -> Self {
+        Self::new(
+            "L004",
+            format!("Number literal `{raw}` is out of range for `{ty}`"),
+        )
+    }
+    #[must_use]
+    pub fn l005(escape: &str) -> Self {
+        Self::new("L005", format!("Invalid escape `{escape}`"))
+    }
+    #[must_use]
+    pub fn l006() -> Self {
+        Self::new("L006", "Text literal is never closed with `\"`".to_owned())
+    }
+    #[must_use]
+    pub fn l007() -> Self {
+        Self::new(
+            "L007",
+            "`{` in a formatted string is never closed with `}`".to_owned(),
+        )
+    }
 }
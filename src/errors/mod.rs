@@ -2,6 +2,7 @@ mod interpreter;
 mod lexer;
 mod parser;
 mod type_check;
+mod warning;
 
 use std::fmt::Debug;
 
@@ -9,12 +10,16 @@ use backtrace::Backtrace;
 use color_eyre::{eyre::eyre, Report, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use smol_str::SmolStr;
 use tracing::{debug, warn};
 use tracing_error::SpanTrace;
 
 use crate::{
     file_importer::get_input,
-    types::position::{GetSpan, Span},
+    types::{
+        position::{GetSpan, Span},
+        value::Value,
+    },
 };
 
 pub type ZResult<T> = Result<T, ZError>;
@@ -26,6 +31,10 @@ pub struct ZError {
     pub message: String,
     pub span_trace: Box<SpanTrace>,
     pub back_trace: Box<Backtrace>,
+    /// The value that `?` returns with, caught by the enclosing procedure
+    pub propagated: Option<Box<Value>>,
+    /// The label of the frame that `propagated` returns to, if it is not the nearest one
+    pub propagated_label: Option<SmolStr>,
 }
 
 impl ZError {
@@ -38,6 +47,8 @@ impl ZError {
             pos: Vec::new(),
             span_trace: Box::new(SpanTrace::capture()),
             back_trace: Box::new(Backtrace::new()),
+            propagated: None,
+            propagated_label: None,
         }
     }
     #[tracing::instrument(skip_all)]
@@ -112,11 +123,19 @@ impl ZError {
         debug!("Span trace:\n{}", self.span_trace);
         debug!("Back trace:\n{:#?}", self.back_trace);
         println!("{}", self.get_surrounding_text()?);
-        println!(
-            " Error {}{} ",
-            self.code.black().on_yellow(),
-            format!(" {}", self.message).bold().red(),
-        );
+        if self.code.starts_with('W') {
+            println!(
+                " Warning {}{} ",
+                self.code.black().on_yellow(),
+                format!(" {}", self.message).bold().yellow(),
+            );
+        } else {
+            println!(
+                " Error {}{} ",
+                self.code.black().on_yellow(),
+                format!(" {}", self.message).bold().red(),
+            );
+        }
         if self.code.starts_with('X') {
             return Err(eyre!("The above error is a compiler error"));
         };
@@ -121,4 +121,77 @@ impl ZError {
     pub fn p024() -> Self {
         Self::new("P024", "Stray `(`".to_owned())
     }
+    #[must_use]
+    pub fn p025() -> Self {
+        Self::new("P025", "Block expected after `do`".to_owned())
+    }
+    #[must_use]
+    pub fn p026() -> Self {
+        Self::new("P026", "`while` expected after `do` block".to_owned())
+    }
+    #[must_use]
+    pub fn p027() -> Self {
+        Self::new("P027", "Expected an ident after `for`".to_owned())
+    }
+    #[must_use]
+    pub fn p028() -> Self {
+        Self::new("P028", "`in` expected after loop variable".to_owned())
+    }
+    #[must_use]
+    pub fn p029() -> Self {
+        Self::new("P029", "Expected a label after `'`".to_owned())
+    }
+    #[must_use]
+    pub fn p030() -> Self {
+        Self::new("P030", "Expected a value and `of` arms after `match`".to_owned())
+    }
+    #[must_use]
+    pub fn p031() -> Self {
+        Self::new("P031", "Expected a pattern".to_owned())
+    }
+    #[must_use]
+    pub fn p032() -> Self {
+        Self::new("P032", "Expected a value before `!!`, `?`, `!?`, `++` or `--`".to_owned())
+    }
+    #[must_use]
+    pub fn p033() -> Self {
+        Self::new("P033", "Expected a type parameter name".to_owned())
+    }
+    #[must_use]
+    pub fn p034(literal: &str) -> Self {
+        Self::new(
+            "P034",
+            format!("`{literal}` is neither a single character nor a hex codepoint"),
+        )
+    }
+    #[must_use]
+    pub fn p035() -> Self {
+        Self::new(
+            "P035",
+            "Expected an expression between `{` and `}` in a formatted string".to_owned(),
+        )
+    }
+    #[must_use]
+    pub fn p036(escape: &str) -> Self {
+        Self::new("P036", format!("Invalid unicode escape `\\u{{{escape}}}`"))
+    }
+    #[must_use]
+    pub fn p037() -> Self {
+        Self::new("P037", "Expected `{` after `enum`".to_owned())
+    }
+    #[must_use]
+    pub fn p038() -> Self {
+        Self::new(
+            "P038",
+            "Expected an enum variant, like `inst A`, `inst A := value` or `inst A: type`"
+                .to_owned(),
+        )
+    }
+    #[must_use]
+    pub fn p039() -> Self {
+        Self::new(
+            "P039",
+            "`$` and `&$` can only be the first argument".to_owned(),
+        )
+    }
 }
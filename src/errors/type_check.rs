@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use crate::{errors::ZError, types::r#type::Type};
+use crate::{
+    errors::ZError,
+    types::{r#type::Type, token::OprType},
+};
 
 impl ZError {
     #[must_use]
@@ -91,4 +94,98 @@ impl ZError {
     pub fn t017() -> Self {
         Self::new("T017", "Unable to return anything here".to_owned())
     }
+    #[must_use]
+    pub fn t018(kwd: &str) -> Self {
+        Self::new("T018", format!("`{kwd}` can only be used inside a loop"))
+    }
+    #[must_use]
+    pub fn t019(label: &str) -> Self {
+        Self::new("T019", format!("No enclosing loop is labelled `{label}`"))
+    }
+    #[must_use]
+    pub fn t020(label: &str) -> Self {
+        Self::new("T020", format!("No enclosing procedure is labelled `{label}`"))
+    }
+    #[must_use]
+    pub fn t021(uncovered: impl Display) -> Self {
+        Self::new(
+            "T021",
+            format!("Match is not exhaustive; `{uncovered}` is not covered"),
+        )
+    }
+    #[must_use]
+    pub fn t022(ty: &Type) -> Self {
+        Self::new("T022", format!("Expected an `opt`, got `{ty}`"))
+    }
+    #[must_use]
+    pub fn t023(ty: &Type) -> Self {
+        Self::new("T023", format!("`{ty}` does not take type arguments"))
+    }
+    #[must_use]
+    pub fn t024(ty: &Type, expected: usize, actual: usize) -> Self {
+        Self::new(
+            "T024",
+            format!("`{ty}` expects {expected} type arguments, got {actual}"),
+        )
+    }
+    #[must_use]
+    pub fn t025(ty: &Type) -> Self {
+        Self::new("T025", format!("Expected a `res`, got `{ty}`"))
+    }
+    #[must_use]
+    pub fn t026(param: &str) -> Self {
+        Self::new(
+            "T026",
+            format!("Unable to infer type argument `{param}` from the arguments"),
+        )
+    }
+    #[must_use]
+    pub fn t027(param: &str) -> Self {
+        Self::new(
+            "T027",
+            format!("Expected a non-negative integer literal for type argument `{param}`"),
+        )
+    }
+    #[must_use]
+    pub fn t028(index: i32, len: usize) -> Self {
+        Self::new(
+            "T028",
+            format!("Index {index} is out of bounds for a tuple of length {len}"),
+        )
+    }
+    #[must_use]
+    pub fn t029(name: &str) -> Self {
+        Self::new("T029", format!("Unexpected keyword argument `{name}`"))
+    }
+    #[must_use]
+    pub fn t031(opr: OprType) -> Self {
+        Self::new("T031", format!("The `{opr}` operator is not supported"))
+    }
+    #[must_use]
+    pub fn t032(name: &str) -> Self {
+        Self::new(
+            "T032",
+            format!("Variant `{name}` is declared more than once"),
+        )
+    }
+    #[must_use]
+    pub fn t033(ty: &Type) -> Self {
+        Self::new(
+            "T033",
+            format!("Cannot inherit from `{ty}`, as it is not a class"),
+        )
+    }
+    #[must_use]
+    pub fn t034() -> Self {
+        Self::new("T034", "`..` can only be used in a class".to_owned())
+    }
+    #[must_use]
+    pub fn t035(name: &str, inherited: &Type, actual: &Type) -> Self {
+        Self::new(
+            "T035",
+            format!(
+                "`{name}` is inherited as `{inherited}`, and cannot be replaced with `{actual}`"
+            ),
+        )
+    }
 }
@@ -0,0 +1,11 @@
+use crate::errors::ZError;
+
+impl ZError {
+    #[must_use]
+    pub fn w001() -> Self {
+        Self::new(
+            "W001",
+            "Unreachable arm: earlier arms already match every value this arm matches".to_owned(),
+        )
+    }
+}
@@ -11,7 +11,7 @@ pub fn interpret_asts(input: &Vec<Ast>, val_symt: &mut InterpretSymTable) -> ZRe
         content: input.to_owned(),
     };
     let mut last = input.interpret_block(val_symt, true, true)?;
-    while let Value::Return(v) = last {
+    while let Value::Return(v, _) = last {
         last = *v;
     }
     if last == Value::Unit {
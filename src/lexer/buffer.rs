@@ -30,8 +30,11 @@ impl Buffer {
         self.content.get(self.cursor)
     }
     pub fn peek(&self) -> Option<(char, Position)> {
+        self.peek_nth(0)
+    }
+    pub fn peek_nth(&self, n: usize) -> Option<(char, Position)> {
         self.content
-            .get(if self.started { self.cursor + 1 } else { 0 })
+            .get(if self.started { self.cursor + 1 } else { 0 } + n)
             .cloned()
     }
 }
@@ -8,6 +8,7 @@ mod word;
 
 use std::sync::Arc;
 
+pub use number::number_value;
 use lazy_regex::lazy_regex;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -24,7 +25,10 @@ use crate::{
         whitespace::{clean_whitespaces, lex_whitespace},
         word::lex_word,
     },
-    types::{position::Position, token::Token},
+    types::{
+        position::Position,
+        token::{Token, TokenType},
+    },
 };
 
 static ALPHANUMERIC: Lazy<Regex> = lazy_regex!(r"^[a-zA-Z0-9_]+$");
@@ -32,22 +36,56 @@ static NUMERIC: Lazy<Regex> = lazy_regex!(r"^[0-9]+$");
 static WHITESPACE: Lazy<Regex> = lazy_regex!(r"^\s+$");
 static ALPHABETIC: Lazy<Regex> = lazy_regex!(r"^[a-zA-Z_]+$");
 
+/// Whether the last token lexed ends an operand, so that a `-` after it is subtraction rather
+/// than the sign of a number
+fn ends_operand(tokens: &[Token]) -> bool {
+    tokens
+        .iter()
+        .rev()
+        .find(|token| !matches!(token.ty, Some(TokenType::Whitespace | TokenType::Comment)))
+        .is_some_and(|token| {
+            matches!(
+                token.ty,
+                Some(
+                    TokenType::Ident
+                        | TokenType::LiteralMisc
+                        | TokenType::LiteralNumber
+                        | TokenType::LiteralString
+                        | TokenType::LiteralChar
+                        | TokenType::LiteralFormatString
+                        | TokenType::PostfixOpr(_)
+                        | TokenType::CloseParen
+                        | TokenType::CloseSquareParen
+                        | TokenType::CloseCurlyParen
+                )
+            )
+        })
+}
+
+#[tracing::instrument(skip_all)]
+pub fn lex(input: String, filename: SmolStr) -> ZResult<Vec<Token>> {
+    let pos = Position {
+        filename: Some(Arc::new(filename)),
+        ..Default::default()
+    };
+    lex_at(input, pos)
+}
+
+/// Lexes `input` as if it starts at `pos`, eg for the expressions embedded in a formatted string
 #[tracing::instrument(skip_all)]
-pub fn lex(mut input: String, filename: SmolStr) -> ZResult<Vec<Token>> {
+pub fn lex_at(mut input: String, pos: Position) -> ZResult<Vec<Token>> {
     if input.trim().is_empty() {
         return Ok(vec![]);
     };
     input.push('\n');
 
-    let pos = Position {
-        filename: Some(Arc::new(filename)),
-        ..Default::default()
-    };
     let mut iter = Buffer::new(&input, pos);
     let mut tokens = vec![];
     while let Some((char, pos)) = iter.to_owned().peek() {
         trace!(?char, ?pos);
-        if char == '"' {
+        if char == '"'
+            || (matches!(char, 'c' | 'f' | 'r') && iter.peek_nth(1).is_some_and(|(c, _)| c == '"'))
+        {
             debug!(?char, ?pos, "Text literal detected");
             lex_text_literal(&mut iter, &mut tokens)?;
         } else if ALPHABETIC.is_match(&char.to_string()) {
@@ -56,7 +94,13 @@ pub fn lex(mut input: String, filename: SmolStr) -> ZResult<Vec<Token>> {
         } else if WHITESPACE.is_match(&char.to_string()) {
             debug!(?char, ?pos, "Whitespace detected");
             lex_whitespace(&mut iter, &mut tokens)?;
-        } else if NUMERIC.is_match(&char.to_string()) {
+        } else if NUMERIC.is_match(&char.to_string())
+            || (char == '-'
+                && iter
+                    .peek_nth(1)
+                    .is_some_and(|(c, _)| NUMERIC.is_match(&c.to_string()))
+                && !ends_operand(&tokens))
+        {
             debug!(?char, ?pos, "Number detected");
             lex_number(&mut iter, &mut tokens)?;
         } else {
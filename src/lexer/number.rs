@@ -1,38 +1,180 @@
+use half::f16;
+use num::{BigInt, ToPrimitive};
 use tracing::trace;
 
 use crate::{
-    errors::ToZResult,
-    lexer::{buffer::Buffer, NUMERIC},
+    errors::{ToZResult, ZError},
+    lexer::{buffer::Buffer, ALPHANUMERIC, NUMERIC},
     types::{
         position::Span,
         token::{Token, TokenType},
+        value::Value,
     },
     ZResult,
 };
 
+const SUFFIXES: [&str; 17] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "ibig", "u8", "u16", "u32", "u64", "u128", "usize",
+    "ubig", "f16", "f32", "f64",
+];
+
+/// Returns the alphanumeric characters starting `n` characters ahead
+fn peek_word(iter: &Buffer, n: usize) -> String {
+    let mut word = String::new();
+    while let Some((char, _)) = iter.peek_nth(n + word.len()) {
+        if !ALPHANUMERIC.is_match(&char.to_string()) {
+            break;
+        }
+        word.push(char);
+    }
+    word
+}
+
 #[tracing::instrument(skip_all)]
 pub fn lex_number(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
     let mut raw = String::new();
     let init_pos = iter.peek().z()?.1;
-    let mut dotted = false;
-    while let Some((char, pos)) = iter.peek() {
-        trace!(?char, ?pos);
-        if NUMERIC.is_match(&char.to_string()) {
-            raw.push(char);
-            iter.next().z()?;
-        } else if char == '.' && !dotted {
-            dotted = true;
-            raw.push(char);
+    let is_digit = |c: char| NUMERIC.is_match(&c.to_string());
+    // the sign of a negative literal, which is part of it so that eg `-128i8` is in range
+    if let Some(('-', _)) = iter.peek() {
+        raw.push('-');
+        iter.next().z()?;
+    }
+    if let (Some(('0', _)), Some(('x' | 'o' | 'b', _))) = (iter.peek(), iter.peek_nth(1)) {
+        // the digits are checked against the radix once the value is parsed
+        let word = peek_word(iter, 0);
+        for _ in word.chars() {
             iter.next().z()?;
-        } else {
-            tokens.push(Token {
-                ty: Some(TokenType::LiteralNumber),
-                value: (&raw).into(),
-                span: Span::new(init_pos, &raw),
-                ..Default::default()
-            });
-            return Ok(());
+        }
+        raw.push_str(&word);
+    } else {
+        let mut dotted = false;
+        let mut exponent = false;
+        while let Some((char, pos)) = iter.peek() {
+            trace!(?char, ?pos);
+            let digit_at = |n| iter.peek_nth(n).is_some_and(|(c, _)| is_digit(c));
+            if is_digit(char) || char == '_' {
+                raw.push(char);
+                iter.next().z()?;
+            } else if char == '.' && !dotted && !exponent && digit_at(1) {
+                dotted = true;
+                raw.push(char);
+                iter.next().z()?;
+            } else if matches!(char, 'e' | 'E') && !exponent && digit_at(1) {
+                exponent = true;
+                raw.push(char);
+                iter.next().z()?;
+            } else if matches!(char, 'e' | 'E')
+                && !exponent
+                && iter
+                    .peek_nth(1)
+                    .is_some_and(|(c, _)| matches!(c, '+' | '-'))
+                && digit_at(2)
+            {
+                exponent = true;
+                raw.push(char);
+                iter.next().z()?;
+                raw.push(iter.next().z()?.0);
+            } else {
+                break;
+            }
+        }
+        let suffix = peek_word(iter, 0);
+        if SUFFIXES.contains(&&*suffix) {
+            for _ in suffix.chars() {
+                iter.next().z()?;
+            }
+            raw.push_str(&suffix);
         }
     }
+    let span = Span::new(init_pos, &raw);
+    number_value(&raw).map_err(|err| err.with_span(&span))?;
+    tokens.push(Token {
+        ty: Some(TokenType::LiteralNumber),
+        value: (&raw).into(),
+        span,
+        ..Default::default()
+    });
     Ok(())
 }
+
+/// Parses a number literal, which may have a sign, a radix prefix, `_` separators, an exponent and
+/// a type suffix. Literals without a suffix are `i32` or `f64`.
+pub fn number_value(raw: &str) -> ZResult<Value> {
+    let (negative, unsigned) = raw
+        .strip_prefix('-')
+        .map_or((false, raw), |unsigned| (true, unsigned));
+    let (radix, body) = match unsigned.get(..2) {
+        Some("0x") => (16, unsigned.get(2..).z()?),
+        Some("0o") => (8, unsigned.get(2..).z()?),
+        Some("0b") => (2, unsigned.get(2..).z()?),
+        _ => (10, unsigned),
+    };
+    // a `_` separates two digits, so it cannot lead, trail or be repeated
+    let is_digit = |c: Option<&u8>| c.is_some_and(|c| char::from(*c).is_digit(radix));
+    if body.bytes().enumerate().any(|(i, c)| {
+        c == b'_'
+            && !(is_digit(i.checked_sub(1).and_then(|i| body.as_bytes().get(i)))
+                && is_digit(body.as_bytes().get(i + 1)))
+    }) {
+        return Err(ZError::l003(raw));
+    }
+    let digits = body.replace('_', "");
+    // `f` is a hex digit, so only integer suffixes can follow a radix prefix
+    let (digits, suffix) = digits.split_at(
+        digits
+            .find(|c| matches!(c, 'i' | 'u') || (radix == 10 && c == 'f'))
+            .unwrap_or(digits.len()),
+    );
+    if !suffix.is_empty() && !SUFFIXES.contains(&suffix) {
+        return Err(ZError::l003(raw));
+    }
+    let out_of_range =
+        |default| ZError::l004(raw, if suffix.is_empty() { default } else { suffix });
+
+    if radix == 10 && (digits.contains(['.', 'e', 'E']) || suffix.starts_with('f')) {
+        let out_of_range = || out_of_range("f64");
+        let n = digits
+            .parse::<f64>()
+            .ok()
+            .ok_or_else(|| ZError::l003(raw))?;
+        let n = if negative { -n } else { n };
+        let value = match suffix {
+            "f16" => Value::F16(f16::from_f64(n)),
+            "f32" => Value::F32(n.to_f32().ok_or_else(out_of_range)?),
+            "f64" | "" => Value::F64(n),
+            _ => return Err(ZError::l003(raw)),
+        };
+        let is_infinite = match value {
+            Value::F16(n) => n.is_infinite(),
+            Value::F32(n) => n.is_infinite(),
+            _ => n.is_infinite(),
+        };
+        return if is_infinite {
+            Err(out_of_range())
+        } else {
+            Ok(value)
+        };
+    }
+
+    let out_of_range = || out_of_range("i32");
+    let n = BigInt::parse_bytes(digits.as_bytes(), radix).ok_or_else(|| ZError::l003(raw))?;
+    let n = if negative { -n } else { n };
+    Ok(match suffix {
+        "i8" => Value::I8(n.to_i8().ok_or_else(out_of_range)?),
+        "i16" => Value::I16(n.to_i16().ok_or_else(out_of_range)?),
+        "i32" => Value::I32(n.to_i32().ok_or_else(out_of_range)?),
+        "i64" => Value::I64(n.to_i64().ok_or_else(out_of_range)?),
+        "i128" => Value::I128(n.to_i128().ok_or_else(out_of_range)?),
+        "isize" => Value::Isize(n.to_isize().ok_or_else(out_of_range)?),
+        "ibig" => Value::Ibig(n),
+        "u8" => Value::U8(n.to_u8().ok_or_else(out_of_range)?),
+        "u16" => Value::U16(n.to_u16().ok_or_else(out_of_range)?),
+        "u32" => Value::U32(n.to_u32().ok_or_else(out_of_range)?),
+        "u64" => Value::U64(n.to_u64().ok_or_else(out_of_range)?),
+        "u128" => Value::U128(n.to_u128().ok_or_else(out_of_range)?),
+        "usize" => Value::Usize(n.to_usize().ok_or_else(out_of_range)?),
+        "ubig" => Value::Ubig(n.to_biguint().ok_or_else(out_of_range)?),
+        _ => Value::I32(n.to_i32().ok_or_else(out_of_range)?),
+    })
+}
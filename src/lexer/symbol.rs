@@ -29,6 +29,11 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     char.push('-');
                     TokenType::BinaryOpr(OprType::AddSub)
                 }
+                Some(('+', _)) => {
+                    iter.next().z()?;
+                    char.push('+');
+                    TokenType::PostfixOpr(OprType::Inc)
+                }
                 _ => TokenType::BinaryOpr(OprType::Add),
             },
             '-' => match iter.peek() {
@@ -42,6 +47,11 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     char.push('+');
                     TokenType::BinaryOpr(OprType::SubAdd)
                 }
+                Some(('-', _)) => {
+                    iter.next().z()?;
+                    char.push('-');
+                    TokenType::PostfixOpr(OprType::Dec)
+                }
                 _ => TokenType::BinaryOpr(OprType::Sub),
             },
             '*' => match iter.peek() {
@@ -107,6 +117,28 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                 _ => TokenType::BinaryOpr(OprType::Concat),
             },
             '@' => TokenType::BinaryOpr(OprType::TypeCast),
+            '\\' => {
+                let Some((next, _)) = iter.next() else {
+                    return Err(ZError::l001().with_span(Span::new(pos, &char)));
+                };
+                char.push(*next);
+                match next {
+                    '~' => TokenType::UnaryOpr(OprType::BitCompl),
+                    '&' => TokenType::BinaryOpr(OprType::BitAnd),
+                    '|' => TokenType::BinaryOpr(OprType::BitOr),
+                    '^' => TokenType::BinaryOpr(OprType::BitXor),
+                    '<' => TokenType::BinaryOpr(OprType::Lsh),
+                    '>' => match iter.peek() {
+                        Some(('>', _)) => {
+                            iter.next().z()?;
+                            char.push('>');
+                            TokenType::BinaryOpr(OprType::Zrsh)
+                        }
+                        _ => TokenType::BinaryOpr(OprType::Rsh),
+                    },
+                    _ => return Err(ZError::l001().with_span(Span::new(pos, &char))),
+                }
+            }
             '=' => match iter.peek() {
                 Some(('=', _)) => {
                     iter.next().z()?;
@@ -121,6 +153,22 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     char.push('=');
                     TokenType::BinaryOpr(OprType::Ne)
                 }
+                Some(('!', _)) => {
+                    iter.next().z()?;
+                    char.push('!');
+                    TokenType::PostfixOpr(OprType::Unwrap)
+                }
+                Some(('?', _)) => {
+                    iter.next().z()?;
+                    char.push('?');
+                    if let Some((':', _)) = iter.peek() {
+                        iter.next().z()?;
+                        char.push(':');
+                        TokenType::BinaryOpr(OprType::Handle)
+                    } else {
+                        TokenType::PostfixOpr(OprType::Propagate)
+                    }
+                }
                 _ => TokenType::UnaryOpr(OprType::Not),
             },
             '>' => match iter.peek() {
@@ -128,7 +176,12 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     iter.next().z()?;
                     char.push('=');
                     TokenType::BinaryOpr(OprType::Ge)
-                } // TODO insertion
+                }
+                Some(('>', _)) => {
+                    iter.next().z()?;
+                    char.push('>');
+                    TokenType::BinaryOpr(OprType::Extract)
+                }
                 _ => TokenType::BinaryOpr(OprType::Gt),
             },
             '<' => match iter.peek() {
@@ -137,6 +190,11 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     char.push('=');
                     TokenType::BinaryOpr(OprType::Le)
                 }
+                Some(('<', _)) => {
+                    iter.next().z()?;
+                    char.push('<');
+                    TokenType::BinaryOpr(OprType::Insert)
+                }
                 _ => TokenType::BinaryOpr(OprType::Lt),
             },
             '&' => match iter.peek() {
@@ -152,10 +210,22 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     iter.next().z()?;
                     char.push('|');
                     TokenType::BinaryOpr(OprType::Or)
-                } // TODO |>
+                }
+                Some(('>', _)) => {
+                    iter.next().z()?;
+                    char.push('>');
+                    TokenType::BinaryOpr(OprType::Pipe)
+                }
                 _ => TokenType::Bar,
             },
-            '.' => TokenType::DotOpr(AccessType::Field),
+            '.' => match iter.peek() {
+                Some(('.', _)) => {
+                    iter.next().z()?;
+                    char.push('.');
+                    TokenType::BinaryOpr(OprType::Range)
+                }
+                _ => TokenType::DotOpr(AccessType::Field),
+            },
             ':' => match iter.peek() {
                 Some(('=', _)) => {
                     iter.next().z()?;
@@ -174,7 +244,32 @@ pub fn lex_symbol(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                 }
                 _ => TokenType::Colon,
             },
+            '?' => match iter.peek() {
+                Some((':', _)) => {
+                    iter.next().z()?;
+                    char.push(':');
+                    TokenType::BinaryOpr(OprType::Coalesce)
+                }
+                Some(('.', _)) => {
+                    iter.next().z()?;
+                    char.push('.');
+                    TokenType::DotOpr(AccessType::OptionalField)
+                }
+                _ => TokenType::PostfixOpr(OprType::Propagate),
+            },
+            // `$` is the receiver of a method, and `$ty` is the type of the class it is in
+            '$' => {
+                while let Some((c, _)) = iter.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    iter.next().z()?;
+                    char.push(c);
+                }
+                TokenType::Ident
+            }
             ';' => TokenType::StatementEnd,
+            '\'' => TokenType::Apostrophe,
             ',' => TokenType::Comma,
             '(' => TokenType::OpenParen,
             '[' => TokenType::OpenSquareParen,
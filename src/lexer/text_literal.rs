@@ -1,7 +1,7 @@
 use tracing::trace;
 
 use crate::{
-    errors::ToZResult,
+    errors::{ToZResult, ZError},
     lexer::buffer::Buffer,
     types::{
         position::Span,
@@ -12,21 +12,70 @@ use crate::{
 
 #[tracing::instrument(skip_all)]
 pub fn lex_text_literal(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
-    let (_, init_pos) = iter.next().z()?.to_owned();
-    let mut raw = "\"".to_owned();
-    while let Some((char, pos)) = iter.next() {
+    let (first, init_pos) = iter.next().z()?.to_owned();
+    // a prefix before the opening quote, eg `c"a"`, changes the kind of literal
+    let ty = match first {
+        'c' => TokenType::LiteralChar,
+        'f' => TokenType::LiteralFormatString,
+        _ => TokenType::LiteralString,
+    };
+    let is_raw = first == 'r';
+    let is_format = first == 'f';
+    let mut raw = if first == '"' {
+        String::new()
+    } else {
+        iter.next().z()?;
+        first.to_string()
+    };
+    raw.push('"');
+    // the expressions in a formatted string may contain strings of their own
+    let mut depth = 0usize;
+    let mut in_inner_str = false;
+    let mut open_brace = None;
+    while let Some((char, pos)) = iter.next().cloned() {
         trace!(?char, ?pos);
-        if *char == '"' {
-            raw.push('"');
-            tokens.push(Token {
-                ty: Some(TokenType::LiteralString),
-                value: (&raw).into(),
-                span: Span::new(init_pos, &raw),
-                ..Default::default()
-            });
-            return Ok(());
+        raw.push(char);
+        match char {
+            '\\' if !is_raw => {
+                let Some((escaped, _)) = iter.next().cloned() else {
+                    break;
+                };
+                raw.push(escaped);
+                // the escapes of a string inside an embedded expression are checked when it is
+                // lexed again
+                let valid = matches!(escaped, '\\' | 'n' | 't' | 'r' | '0' | 'u' | '"')
+                    || (is_format && matches!(escaped, '{' | '}'))
+                    || depth > 0;
+                if !valid {
+                    let escape = format!("\\{escaped}");
+                    return Err(ZError::l005(&escape).with_span(Span::new(pos, &escape)));
+                }
+            }
+            '"' if depth > 0 => in_inner_str = !in_inner_str,
+            '{' if is_format && !in_inner_str => {
+                if depth == 0 {
+                    open_brace = Some(pos);
+                }
+                depth += 1;
+            }
+            '}' if is_format && !in_inner_str && depth > 0 => depth -= 1,
+            '"' => {
+                tokens.push(Token {
+                    ty: Some(ty),
+                    value: (&raw).into(),
+                    span: Span::new(init_pos, &raw),
+                    ..Default::default()
+                });
+                return Ok(());
+            }
+            _ => (),
+        }
+    }
+    match open_brace {
+        Some(pos) if depth > 0 => Err(ZError::l007().with_span(Span::new(pos, "{"))),
+        _ => {
+            let opening = raw.get(..=raw.find('"').z()?).z()?;
+            Err(ZError::l006().with_span(Span::new(init_pos, opening)))
         }
-        raw.push(*char);
     }
-    Ok(())
 }
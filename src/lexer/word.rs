@@ -5,7 +5,7 @@ use crate::{
     lexer::{buffer::Buffer, ALPHANUMERIC},
     types::{
         position::Span,
-        token::{Flag, Keyword, Token, TokenType},
+        token::{Flag, Keyword, OprType, Token, TokenType},
     },
     ZResult,
 };
@@ -23,12 +23,24 @@ pub fn lex_word(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                 ty: Some(match raw.as_str() {
                     "true" => TokenType::LiteralMisc,
                     "false" => TokenType::LiteralMisc,
+                    "null" => TokenType::LiteralMisc,
                     "if" => TokenType::Keyword(Keyword::If),
                     "else" => TokenType::Keyword(Keyword::Else),
                     "elif" => TokenType::Keyword(Keyword::Elif),
                     "do" => TokenType::Keyword(Keyword::Do),
                     "while" => TokenType::Keyword(Keyword::While),
                     "for" => TokenType::Keyword(Keyword::For),
+                    "in" => TokenType::Keyword(Keyword::In),
+                    "break" => TokenType::Keyword(Keyword::Break),
+                    "continue" => TokenType::Keyword(Keyword::Continue),
+                    "match" => TokenType::Keyword(Keyword::Match),
+                    "of" => TokenType::Keyword(Keyword::Of),
+                    "istype" => TokenType::BinaryOpr(OprType::Istype),
+                    "isnttype" => TokenType::BinaryOpr(OprType::Isnttype),
+                    "is" => TokenType::BinaryOpr(OprType::Is),
+                    "isnt" => TokenType::BinaryOpr(OprType::Isnt),
+                    "iseq" => TokenType::BinaryOpr(OprType::Iseq),
+                    "isnteq" => TokenType::BinaryOpr(OprType::Isnteq),
                     "del" => TokenType::Keyword(Keyword::Delete),
                     "ret" => TokenType::Keyword(Keyword::Return),
                     "proc" => TokenType::Keyword(Keyword::Proc),
@@ -37,6 +49,7 @@ pub fn lex_word(iter: &mut Buffer, tokens: &mut Vec<Token>) -> ZResult<()> {
                     "defer" => TokenType::Keyword(Keyword::Defer),
                     "class" => TokenType::Keyword(Keyword::Class),
                     "struct" => TokenType::Keyword(Keyword::Struct),
+                    "enum" => TokenType::Keyword(Keyword::Enum),
                     "const" => TokenType::Flag(Flag::Const),
                     "hoi" => TokenType::Flag(Flag::Hoi),
                     "pub" => TokenType::Flag(Flag::Pub),
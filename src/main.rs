@@ -43,7 +43,11 @@ fn main() -> Result<()> {
         Subcmd::Run(sargs) => {
             let mut ty_symt = TypeCheckSymTable::default();
             let mut val_symt = InterpretSymTable::default();
-            let compiled = match zyxt::compile(&Either::Left(&sargs.filename), &mut ty_symt, true) {
+            let compiled = zyxt::compile(&Either::Left(&sargs.filename), &mut ty_symt, true);
+            for warning in ty_symt.take_warnings() {
+                warning.print()?;
+            }
+            let compiled = match compiled {
                 Ok(v) => v,
                 Err(e) => {
                     e.print()?;
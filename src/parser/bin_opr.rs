@@ -5,7 +5,7 @@ use crate::{
     ast::{Ast, BinaryOpr},
     errors::{ZError, ZResult},
     parser::buffer::Buffer,
-    types::token::{Token, TokenType},
+    types::token::{OprType, Token, TokenType},
 };
 
 impl Buffer {
@@ -30,7 +30,10 @@ impl Buffer {
             if i == 0 || i == self.content.len() - 1 {
                 return Err(ZError::p006().with_span(selected));
             }
-            if opr_type.order() >= highest_order {
+            // `^` is right-associative, so the leftmost one is split on
+            if opr_type.order() > highest_order
+                || (opr_type.order() == highest_order && *opr_type != OprType::Pow)
+            {
                 highest_order_index = i;
                 highest_order = opr_type.order();
                 opr_ref = Some((tok, opr_type));
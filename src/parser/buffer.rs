@@ -4,6 +4,7 @@ use itertools::Either;
 use tracing::trace;
 
 use crate::{
+    ast::Ident,
     errors::ZError,
     types::{
         position::GetSpan,
@@ -75,6 +76,31 @@ impl Buffer {
         }
         Ok(())
     }
+    pub fn label(&mut self) -> ZResult<Option<Ident>> {
+        if !matches!(
+            self.peek(),
+            Some(Either::Right(Token {
+                ty: Some(TokenType::Apostrophe),
+                ..
+            }))
+        ) {
+            return Ok(None);
+        }
+        self.next();
+        match self.next_or_err()? {
+            Either::Right(Token {
+                ty: Some(TokenType::Ident),
+                value: name,
+                span: name_span,
+                ..
+            }) => Ok(Some(Ident {
+                name,
+                name_span: Some(name_span),
+            })),
+            Either::Left(Ast::Ident(ident)) => Ok(Some(ident)),
+            selected => Err(ZError::p029().with_span(selected)),
+        }
+    }
     pub fn rest_incl_curr(&mut self) -> BufferWindow {
         self.window(self.cursor..self.content.len())
     }
@@ -156,6 +182,8 @@ impl Buffer {
         divider: TokenType,
     ) -> ZResult<BufferWindows> {
         let mut nest_level = 1usize;
+        // dividers in other brackets, like the comma in `|r: res[T, E]|`, are not split on
+        let mut bracket_level = 0usize;
         let bet_start = self.cursor;
         let mut start = self.cursor + 1;
         let mut buffer_windows = vec![];
@@ -167,8 +195,26 @@ impl Buffer {
                     nest_level += 1;
                 } else if ele.ty == Some(end_token) {
                     nest_level -= 1;
+                } else if matches!(
+                    ele.ty,
+                    Some(
+                        TokenType::OpenParen
+                            | TokenType::OpenSquareParen
+                            | TokenType::OpenCurlyParen
+                    )
+                ) {
+                    bracket_level += 1;
+                } else if matches!(
+                    ele.ty,
+                    Some(
+                        TokenType::CloseParen
+                            | TokenType::CloseSquareParen
+                            | TokenType::CloseCurlyParen
+                    )
+                ) {
+                    bracket_level = bracket_level.saturating_sub(1);
                 }
-                if nest_level == 1 && ele.ty == Some(divider) {
+                if nest_level == 1 && bracket_level == 0 && ele.ty == Some(divider) {
                     trace!(pos = ?ele.span(), "Split");
                     buffer_windows.push(self.window(start..self.cursor).to_owned());
                     start = self.next_cursor_pos();
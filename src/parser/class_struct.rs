@@ -59,6 +59,7 @@ impl Buffer {
                 None
             };
             let ele = Ast::Class(Class::Raw {
+                name: None,
                 kwd_span: span,
                 is_struct: kwd == Keyword::Struct,
                 content,
@@ -40,12 +40,34 @@ impl Buffer {
                 continue;
             }
 
-            start.get_or_insert(self.cursor - 1);
             let eq_span = selected.span();
 
-            let declared_var = if let Some(Either::Left(d)) = self.peek_prev() {
+            // `x: T := ...`, where `T` can be any expression
+            let colon_pos = self.content[..self.cursor]
+                .iter()
+                .rposition(|ele| {
+                    matches!(
+                        ele,
+                        Either::Right(Token {
+                            ty: Some(TokenType::Colon),
+                            ..
+                        })
+                    )
+                })
+                .filter(|pos| *pos > start.unwrap_or(0) && *pos + 1 < self.cursor);
+            let (var_pos, ty) = if let Some(colon_pos) = colon_pos {
+                let ty = self
+                    .window(colon_pos + 1..self.cursor)
+                    .with_as_buffer(&Self::parse_as_expr)?;
+                (colon_pos - 1, Some(ty.into()))
+            } else {
+                (self.cursor - 1, None)
+            };
+            start.get_or_insert(var_pos);
+
+            let declared_var = if let Some(Either::Left(d)) = self.content.get(var_pos) {
                 d.to_owned()
-            } else if let Some(d) = self.peek_prev() {
+            } else if let Some(d) = self.content.get(var_pos) {
                 return Err(ZError::p012().with_span(d));
             } else {
                 return Err(ZError::p008().with_span(selected));
@@ -53,7 +75,7 @@ impl Buffer {
             debug!(pos = ?declared_var.span(), "Parsing declaration");
 
             let flags = if let Some(flag_pos) = flag_pos {
-                self.content[flag_pos..self.cursor - 1]
+                self.content[flag_pos..var_pos]
                     .iter()
                     .map(|ele| {
                         if let Either::Right(Token {
@@ -78,7 +100,7 @@ impl Buffer {
                 variable: declared_var.to_owned().into(),
                 content: content.into(),
                 flags,
-                ty: None,
+                ty,
                 eq_span,
             });
             trace!(?ele);
@@ -0,0 +1,117 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, Enum, EnumVariant, Ident},
+    errors::{ZError, ZResult},
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{Flag, Keyword, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_enum(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        while let Some(selected) = self.next() {
+            if !matches!(
+                selected,
+                Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::Enum)),
+                    ..
+                })
+            ) {
+                continue;
+            }
+            let start = self.cursor;
+            let kwd_span = selected.span();
+            debug!(pos = ?kwd_span, "Parsing enum");
+
+            let Some(Either::Right(Token {
+                ty: Some(TokenType::OpenCurlyParen),
+                span: open_span,
+                ..
+            })) = self.next()
+            else {
+                return Err(ZError::p037().with_span(selected));
+            };
+            let mut variants = self.get_split_between(
+                TokenType::OpenCurlyParen,
+                TokenType::CloseCurlyParen,
+                TokenType::StatementEnd,
+            )?;
+            let close_span = self.this().span();
+            let variants = variants
+                .with_as_buffers(&Self::parse_enum_variant)?
+                .into_iter()
+                .flatten()
+                .collect();
+            let ele = Ast::Enum(Enum {
+                kwd_span,
+                brace_spans: close_span.map(|close_span| (open_span, close_span)),
+                variants,
+                name: None,
+            });
+            trace!(?ele);
+            let buffer_window = BufferWindow {
+                slice: vec![Either::Left(ele)],
+                range: start..self.next_cursor_pos(),
+            };
+            self.splice_buffer(buffer_window);
+        }
+        Ok(())
+    }
+
+    /// Parses `inst A`, `inst A := value` or `inst A: T`, where `inst` is optional
+    fn parse_enum_variant(&mut self) -> ZResult<Option<EnumVariant>> {
+        let name_pos = usize::from(matches!(
+            self.content.first(),
+            Some(Either::Right(Token {
+                ty: Some(TokenType::Flag(Flag::Inst)),
+                ..
+            }))
+        ));
+        if self.content.is_empty() {
+            return Ok(None);
+        }
+        let Some(Either::Right(Token {
+            ty: Some(TokenType::Ident),
+            value,
+            span,
+            ..
+        })) = self.content.get(name_pos)
+        else {
+            return Err(ZError::p038().with_span(self.content.get(name_pos)));
+        };
+        let name = Ident {
+            name: value.to_owned(),
+            name_span: Some(span.to_owned()),
+        };
+        debug!(pos = ?name.span(), "Parsing enum variant");
+        let (value, ty) = match self.content.get(name_pos + 1) {
+            None => (None, None),
+            Some(Either::Right(Token {
+                ty: Some(ty @ (TokenType::DeclarationOpr | TokenType::Colon)),
+                ..
+            })) if name_pos + 2 < self.content.len() => {
+                let content = self
+                    .window(name_pos + 2..self.content.len())
+                    .with_as_buffer(&Self::parse_as_expr)?;
+                if *ty == TokenType::Colon {
+                    (None, Some(content))
+                } else {
+                    (Some(content), None)
+                }
+            }
+            Some(ele) => return Err(ZError::p038().with_span(ele)),
+        };
+        Ok(Some(EnumVariant {
+            name,
+            value,
+            ty,
+            fields: vec![],
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, For, Ident},
+    errors::{ZError, ZResult},
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{Keyword, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_for(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        while let Some(selected) = self.next() {
+            if !matches!(
+                selected,
+                Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::For)),
+                    ..
+                })
+            ) {
+                continue;
+            }
+            let start = self.cursor;
+            let kwd_span = selected.span();
+            debug!(pos = ?kwd_span, "Parsing for");
+            let label = self.label()?;
+
+            let selected = self.next_or_err()?;
+            let Either::Right(Token {
+                ty: Some(TokenType::Ident),
+                value: name,
+                span: name_span,
+                ..
+            }) = selected else {
+                return Err(ZError::p027().with_span(selected));
+            };
+            let variable = Ident {
+                name,
+                name_span: Some(name_span),
+            };
+
+            let selected = self.next_or_err()?;
+            let Either::Right(Token {
+                ty: Some(TokenType::Keyword(Keyword::In)),
+                span: in_span,
+                ..
+            }) = selected else {
+                return Err(ZError::p028().with_span(selected));
+            };
+
+            self.next_or_err()?;
+            let iterable_start = self.cursor;
+            while !matches!(self.peek(), Some(Either::Left(Ast::Block(_))) | None) {
+                self.next();
+            }
+            let iterable = self
+                .window(iterable_start..self.next_cursor_pos())
+                .with_as_buffer(&Self::parse_as_expr)?;
+
+            let selected = self.next_or_err()?;
+            let Either::Left(Ast::Block(content)) = selected else {
+                return Err(ZError::p018().with_span(selected));
+            };
+            let ele = Ast::For(For {
+                kwd_span,
+                label,
+                variable,
+                in_span: Some(in_span),
+                iterable: iterable.into(),
+                content,
+            });
+            trace!(?ele);
+            let buffer_window = BufferWindow {
+                slice: vec![Either::Left(ele)],
+                range: start..self.next_cursor_pos(),
+            };
+            self.splice_buffer(buffer_window);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, FormatString, Literal},
+    errors::{ToZResult, ZError, ZResult},
+    lexer::lex_at,
+    parser::buffer::Buffer,
+    types::{
+        position::{GetSpan, Position, Span},
+        token::Token,
+        value::Value,
+    },
+};
+
+impl Buffer {
+    fn format_string_literal(raw: &str, start: Position) -> ZResult<Ast> {
+        Ok(Ast::Literal(Literal {
+            content: Value::Str(Self::parse_str_literal(raw)?),
+            span: Some(Span::new(start, raw)),
+        }))
+    }
+    /// Splits `f"...{expr}..."` into its string parts and the embedded expressions, which are
+    /// lexed from where they are in the file so that their spans point into the string
+    pub fn parse_format_string(token: &Token) -> ZResult<Ast> {
+        debug!(pos = ?token.span, "Parsing formatted string");
+        let mut pos = token.span.start_pos.to_owned();
+        let mut chars = vec![];
+        for c in token.value.chars() {
+            chars.push((c, pos.to_owned()));
+            pos.next_char(c);
+        }
+        // skip `f"` and the closing `"`
+        chars.pop();
+        let mut chars = chars.into_iter().skip(2).peekable();
+
+        let mut parts = vec![];
+        let mut literal: Option<(String, Position)> = None;
+        while let Some((c, pos)) = chars.next() {
+            if c != '{' {
+                let (raw, _) = literal.get_or_insert_with(|| (String::new(), pos));
+                raw.push(c);
+                if c == '\\' {
+                    raw.push(chars.next().z()?.0);
+                }
+                continue;
+            }
+            if let Some((raw, start)) = literal.take() {
+                parts.push(Self::format_string_literal(&raw, start)?);
+            }
+            let start = chars.peek().map_or(pos, |(_, pos)| pos.to_owned());
+            let mut expr = String::new();
+            let mut depth = 0usize;
+            let mut in_str = false;
+            loop {
+                let Some((c, _)) = chars.next() else {
+                    return Err(ZError::p035().with_span(token));
+                };
+                match c {
+                    '\\' if in_str => {
+                        expr.push(c);
+                        expr.push(chars.next().z()?.0);
+                        continue;
+                    }
+                    '"' => in_str = !in_str,
+                    '{' if !in_str => depth += 1,
+                    '}' if !in_str && depth == 0 => break,
+                    '}' if !in_str => depth -= 1,
+                    _ => (),
+                }
+                expr.push(c);
+            }
+            let tokens = lex_at(expr, start)?;
+            if tokens.is_empty() {
+                return Err(ZError::p035().with_span(token));
+            }
+            let mut buffer = Self::new(tokens);
+            parts.push(buffer.parse_as_expr()?);
+        }
+        if let Some((raw, start)) = literal {
+            parts.push(Self::format_string_literal(&raw, start)?);
+        }
+        Ok(Ast::FormatString(FormatString {
+            parts,
+            span: token.span(),
+        }))
+    }
+}
@@ -0,0 +1,44 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, LoopControl},
+    errors::ZResult,
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{Keyword, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_loop_control(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        while let Some(selected) = self.next() {
+            let Either::Right(Token {
+                ty: Some(TokenType::Keyword(kwd @ (Keyword::Break | Keyword::Continue))),
+                ..
+            }) = selected else {
+                continue;
+            };
+            let start = self.cursor;
+            let kwd_span = selected.span();
+            debug!(pos = ?kwd_span, "Parsing {kwd:?}");
+            let label = self.label()?;
+
+            let ele = Ast::LoopControl(LoopControl {
+                is_continue: kwd == Keyword::Continue,
+                kwd_span,
+                label,
+            });
+            trace!(?ele);
+            let buffer_window = BufferWindow {
+                slice: vec![Either::Left(ele)],
+                range: start..self.next_cursor_pos(),
+            };
+            self.splice_buffer(buffer_window);
+        }
+        Ok(())
+    }
+}
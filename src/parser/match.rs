@@ -0,0 +1,114 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, Match, MatchArm},
+    errors::{ZError, ZResult},
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{Keyword, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_match(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        while let Some(selected) = self.next() {
+            if !matches!(
+                selected,
+                Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::Match)),
+                    ..
+                })
+            ) {
+                continue;
+            }
+            let start = self.cursor;
+            let kwd_span = selected.span();
+            debug!(pos = ?kwd_span, "Parsing match");
+
+            let subject_start = self.next_cursor_pos();
+            while !matches!(
+                self.peek(),
+                Some(Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::Of)),
+                    ..
+                })) | None
+            ) {
+                self.next();
+            }
+            if self.peek().is_none() || subject_start == self.next_cursor_pos() {
+                return Err(ZError::p030().with_span(selected));
+            }
+            let subject = self
+                .window(subject_start..self.next_cursor_pos())
+                .with_as_buffer(&Self::parse_as_expr)?;
+
+            let mut arms = vec![];
+            while let Some(Either::Right(Token {
+                ty: Some(TokenType::Keyword(kwd @ (Keyword::Of | Keyword::Else))),
+                span: arm_kwd_span,
+                ..
+            })) = self.peek().cloned()
+            {
+                self.next();
+                let pattern = if kwd == Keyword::Of {
+                    debug!(pos = ?arm_kwd_span, "Parsing `of` arm");
+                    let pattern_start = self.next_cursor_pos();
+                    while !matches!(self.peek(), Some(Either::Left(Ast::Block(_))) | None) {
+                        self.next();
+                    }
+                    if pattern_start == self.next_cursor_pos() {
+                        return Err(ZError::p031().with_span(&arm_kwd_span));
+                    }
+                    Some(
+                        self.window(pattern_start..self.next_cursor_pos())
+                            .with_as_buffer(&Self::parse_pattern)?,
+                    )
+                } else {
+                    debug!(pos = ?arm_kwd_span, "Parsing `else` arm");
+                    None
+                };
+                let selected = self.next_or_err()?;
+                let Either::Left(Ast::Block(content)) = selected else {
+                    return Err(ZError::p018().with_span(selected));
+                };
+                arms.push(MatchArm {
+                    kwd_span: Some(arm_kwd_span),
+                    pattern,
+                    content,
+                });
+                if kwd == Keyword::Else {
+                    if let Some(
+                        ele @ Either::Right(Token {
+                            ty: Some(TokenType::Keyword(kwd @ (Keyword::Of | Keyword::Else))),
+                            ..
+                        }),
+                    ) = self.peek()
+                    {
+                        return Err(
+                            ZError::p017(if *kwd == Keyword::Of { "of" } else { "else" })
+                                .with_span(ele),
+                        );
+                    }
+                    break;
+                }
+            }
+
+            let ele = Ast::Match(Match {
+                kwd_span,
+                subject: subject.into(),
+                arms,
+            });
+            trace!(?ele);
+            let buffer_window = BufferWindow {
+                slice: vec![Either::Left(ele)],
+                range: start..self.next_cursor_pos(),
+            };
+            self.splice_buffer(buffer_window);
+        }
+        Ok(())
+    }
+}
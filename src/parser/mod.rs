@@ -4,14 +4,22 @@ mod buffer;
 mod class_struct;
 mod declaration;
 mod delete;
+mod r#enum;
+mod r#for;
+mod format_string;
 mod r#if;
+mod loop_control;
+mod r#match;
 mod parentheses;
+mod pattern;
 mod preprocess_defer;
 mod proc_fn;
 mod r#return;
+mod spread;
 mod un_opr;
 mod unparen_call;
 mod var_literal_call;
+mod r#while;
 
 use itertools::Either;
 use smol_str::SmolStr;
@@ -51,16 +59,21 @@ impl Buffer {
     }
     fn parse_as_expr(&mut self) -> ZResult<Ast> {
         self.parse_parentheses()?;
+        self.parse_match()?;
         self.parse_if()?;
+        self.parse_while()?;
+        self.parse_for()?;
+        self.parse_loop_control()?;
         self.parse_class_struct()?;
         self.parse_proc_fn()?;
         self.parse_preprocess_defer()?;
-        //self.parse_enum()?;
+        self.parse_enum()?;
         self.parse_var_literal_call()?;
         self.parse_delete()?;
         self.parse_return()?;
         self.parse_declaration()?;
         self.parse_assignment_opr()?;
+        self.parse_spread()?;
         self.parse_bin_opr()?;
         self.parse_un_opr()?;
         self.parse_unparen_call()?;
@@ -3,7 +3,7 @@ use tracing::{debug, trace};
 
 use crate::{
     parser::buffer::Buffer,
-    types::token::{TokenCategory, TokenType},
+    types::token::{Keyword, Token, TokenCategory, TokenType},
     ZResult,
 };
 
@@ -45,6 +45,14 @@ impl Buffer {
                 })?;
                 self.splice_buffer(paren_window);
             } else if selected.ty == Some(TokenType::OpenCurlyParen) {
+                // the variants of an enum are not statements, so `parse_enum` splits them itself
+                if let Some(Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::Enum)),
+                    ..
+                })) = self.peek_prev()
+                {
+                    continue;
+                }
                 debug!(pos = ?selected.span, "Parsing curly braces");
                 self.parse_as_block()?;
             }
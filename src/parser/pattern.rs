@@ -0,0 +1,127 @@
+use itertools::Either;
+use tracing::debug;
+
+use crate::{
+    ast::{Ast, Ident, Pattern},
+    errors::{ZError, ZResult},
+    parser::buffer::Buffer,
+    types::{
+        position::GetSpan,
+        token::{OprType, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_pattern(&mut self) -> ZResult<Pattern> {
+        let as_ident = |ele: Option<&Either<Ast, Token>>| match ele {
+            Some(Either::Right(Token {
+                ty: Some(TokenType::Ident),
+                value,
+                span,
+                ..
+            })) => Some(Ident {
+                name: value.to_owned(),
+                name_span: Some(span.to_owned()),
+            }),
+            _ => None,
+        };
+        let as_istype = |ele: Option<&Either<Ast, Token>>| match ele {
+            Some(Either::Right(Token {
+                ty: Some(TokenType::BinaryOpr(OprType::Istype)),
+                span,
+                ..
+            })) => Some(span.to_owned()),
+            _ => None,
+        };
+
+        let first = as_ident(self.content.first());
+        if let (Some(ident), 1) = (&first, self.content.len()) {
+            return Ok(if ident.name == "_" {
+                debug!(pos = ?ident.span(), "Wildcard pattern detected");
+                Pattern::Wildcard(ident.name_span.to_owned())
+            } else {
+                debug!(pos = ?ident.span(), "Binding pattern detected");
+                Pattern::Binding(ident.to_owned())
+            });
+        }
+
+        let (binding, istype_pos) = if first.is_some() {
+            (first.filter(|ident| ident.name != "_"), 1)
+        } else {
+            (None, 0)
+        };
+        if let Some(kwd_span) = as_istype(self.content.get(istype_pos)) {
+            debug!(pos = ?kwd_span, "Type pattern detected");
+            if istype_pos + 1 == self.content.len() {
+                return Err(ZError::p031().with_span(&kwd_span));
+            }
+            let ty = self
+                .window(istype_pos + 1..self.content.len())
+                .with_as_buffer(&Self::parse_as_expr)?;
+            return Ok(Pattern::Type {
+                binding,
+                kwd_span: Some(kwd_span),
+                ty: ty.into(),
+            });
+        }
+
+        let paren_pos = self.content.iter().position(|ele| {
+            matches!(
+                ele,
+                Either::Right(Token {
+                    ty: Some(TokenType::OpenParen),
+                    ..
+                })
+            )
+        });
+        if let Some(paren_pos @ 1..) = paren_pos {
+            debug!(pos = ?self.content[paren_pos].span(), "Struct pattern detected");
+            let ty = self
+                .window(0..paren_pos)
+                .with_as_buffer(&Self::parse_as_expr)?;
+            self.cursor = paren_pos;
+            self.started = true;
+            let open_span = self.this().span();
+            let mut fields = self.get_split_between(
+                TokenType::OpenParen,
+                TokenType::CloseParen,
+                TokenType::Comma,
+            )?;
+            let close_span = self.this().span();
+            if let Some(ele) = self.peek() {
+                return Err(ZError::p002().with_span(ele));
+            }
+            let fields = fields.with_as_buffers(&|buf| {
+                let Some(name) = as_ident(buf.content.first()) else {
+                    return Err(ZError::p031().with_span(buf.content.first()));
+                };
+                if buf.content.len() == 1 {
+                    return Ok((name.to_owned(), Pattern::Binding(name)));
+                }
+                if !matches!(
+                    buf.content.get(1),
+                    Some(Either::Right(Token {
+                        ty: Some(TokenType::Colon),
+                        ..
+                    }))
+                ) || buf.content.len() == 2
+                {
+                    return Err(ZError::p031().with_span(buf.content.get(1)));
+                }
+                let pattern = buf
+                    .window(2..buf.content.len())
+                    .with_as_buffer(&Self::parse_pattern)?;
+                Ok((name, pattern))
+            })?;
+            return Ok(Pattern::Struct {
+                ty: ty.into(),
+                paren_spans: open_span.and_then(|open_span| Some((open_span, close_span?))),
+                fields,
+            });
+        }
+
+        debug!(pos = ?self.content.first().span(), "Value pattern detected");
+        Ok(Pattern::Value(self.parse_as_expr()?.into()))
+    }
+}
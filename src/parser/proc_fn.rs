@@ -2,12 +2,12 @@ use itertools::Either;
 use tracing::{debug, trace};
 
 use crate::{
-    ast::{Argument, Ast, Block, Procedure},
+    ast::{Argument, Ast, Block, Ident, Procedure, UnaryOpr},
     errors::{ZError, ZResult},
     parser::buffer::{Buffer, BufferWindow},
     types::{
         position::GetSpan,
-        token::{Keyword, Token, TokenType},
+        token::{Keyword, OprType, Token, TokenType},
     },
 };
 
@@ -21,6 +21,26 @@ impl Buffer {
             let arg_sections = buf
                 .get_split(TokenType::Colon)?
                 .with_as_buffers(&Self::parse_as_expr)?;
+            // `$` and `&$` are the receiver of a method, of the type of its class
+            if let [Ast::Ident(ident)
+            | Ast::UnaryOpr(UnaryOpr {
+                ty: OprType::Ref,
+                operand: box Ast::Ident(ident),
+                ..
+            })] = &*arg_sections
+            {
+                if ident.name == "$" {
+                    debug!(pos = ?ident.span(), "Receiver detected");
+                    return Ok(Argument {
+                        name: ident.to_owned(),
+                        ty: Box::new(Ast::Ident(Ident {
+                            name: "$ty".into(),
+                            name_span: None,
+                        })),
+                        default: None,
+                    });
+                }
+            }
             let name = if let Some(name) = arg_sections.first() {
                 if let Ast::Ident(ident) = name {
                     debug!(pos = ?name.span(), "Name detected");
@@ -43,6 +63,33 @@ impl Buffer {
         })
     }
     #[tracing::instrument(skip_all)]
+    pub fn parse_type_params(&mut self) -> ZResult<Vec<Ident>> {
+        if !matches!(
+            self.peek(),
+            Some(Either::Right(Token {
+                ty: Some(TokenType::OpenSquareParen),
+                ..
+            }))
+        ) {
+            return Ok(vec![]);
+        }
+        self.next();
+        let mut windows = self.get_split_between(
+            TokenType::OpenSquareParen,
+            TokenType::CloseSquareParen,
+            TokenType::Comma,
+        )?;
+        windows.with_as_buffers(&|buf| {
+            let param = buf.parse_as_expr()?;
+            if let Ast::Ident(ident) = param {
+                debug!(pos = ?ident.span(), "Type parameter detected");
+                Ok(ident)
+            } else {
+                Err(ZError::p033().with_span(&param))
+            }
+        })
+    }
+    #[tracing::instrument(skip_all)]
     pub fn parse_proc_fn(&mut self) -> ZResult<()> {
         self.reset_cursor();
         while let Some(mut selected) = self.next() {
@@ -73,21 +120,45 @@ impl Buffer {
             } else {
                 ty == TokenType::Keyword(Keyword::Fn)
             };
-            if ty != TokenType::Bar {
+            let mut type_params = if ty == TokenType::Bar {
+                vec![]
+            } else {
+                self.parse_type_params()?
+            };
+            let label = if ty == TokenType::Bar {
+                None
+            } else {
+                let label = self.label()?;
+                // the type parameters may also come after the label, eg `fn'f[T]`
+                if type_params.is_empty() {
+                    type_params = self.parse_type_params()?;
+                }
                 selected = self.next_or_err()?;
-            }
+                label
+            };
             debug!(is_fn);
-            let args = if let Either::Right(Token {
+            let (args, updates_receiver) = if let Either::Right(Token {
                 ty: Some(TokenType::Bar),
                 span: pos,
                 ..
             }) = &selected
             {
                 debug!(?pos, "Argument list detected");
-                self.parse_args()?
+                let updates_receiver = matches!(
+                    self.content.get(self.cursor + 1),
+                    Some(Either::Right(Token {
+                        ty: Some(TokenType::UnaryOpr(OprType::Ref)),
+                        ..
+                    }))
+                );
+                let args = self.parse_args()?;
+                if let Some(arg) = args.iter().skip(1).find(|arg| arg.name.name == "$") {
+                    return Err(ZError::p039().with_span(&arg.name));
+                }
+                (args, updates_receiver)
             } else {
                 self.cursor -= 1;
-                vec![]
+                (vec![], false)
             };
             selected = self.next_or_err()?;
             let return_type = if let Either::Right(Token {
@@ -136,7 +207,10 @@ impl Buffer {
             let ele = Ast::Procedure(Procedure {
                 is_fn,
                 kwd_span,
+                label,
+                type_params,
                 args,
+                updates_receiver,
                 return_type: return_type.map(Into::into),
                 content: block,
             });
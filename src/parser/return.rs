@@ -26,8 +26,10 @@ impl Buffer {
             ) {
                 continue;
             }
+            let start = self.cursor;
             let kwd_span = selected.span();
             debug!(pos = ?kwd_span, "Parsing return");
+            let label = self.label()?;
             let value = if self.next().is_some() {
                 self.rest_incl_curr().with_as_buffer(&Self::parse_as_expr)?
             } else {
@@ -35,11 +37,15 @@ impl Buffer {
             }
             .into();
 
-            let ele = Ast::Return(Return { kwd_span, value });
+            let ele = Ast::Return(Return {
+                kwd_span,
+                label,
+                value,
+            });
             trace!(?ele);
             let buffer_window = BufferWindow {
                 slice: vec![Either::Left(ele)],
-                range: self.cursor - 1..self.content.len(),
+                range: start..self.content.len(),
             };
             self.splice_buffer(buffer_window);
         }
@@ -0,0 +1,44 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, Spread},
+    errors::ZResult,
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{OprType, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_spread(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        // `..` is a spread only when it starts the expression, otherwise it is a range
+        let Some(
+            selected @ Either::Right(Token {
+                ty: Some(TokenType::BinaryOpr(OprType::Range)),
+                ..
+            }),
+        ) = self.next()
+        else {
+            return Ok(());
+        };
+        let dots_span = selected.span();
+        debug!(pos = ?dots_span, "Parsing spread");
+        self.next_or_err()?;
+        let content = self
+            .rest_incl_curr()
+            .with_as_buffer(&Self::parse_as_expr)?
+            .into();
+        let ele = Ast::Spread(Spread { dots_span, content });
+        trace!(?ele);
+        let buffer_window = BufferWindow {
+            slice: vec![Either::Left(ele)],
+            range: 0..self.content.len(),
+        };
+        self.splice_buffer(buffer_window);
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ impl Buffer {
             };
             let opr_span = selected.span();
             debug!(pos = ?opr_span);
+            self.next_or_err()?;
             let operand = self
                 .rest_incl_curr()
                 .with_as_buffer(&Self::parse_as_expr)?
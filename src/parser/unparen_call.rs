@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use itertools::Either;
 use tracing::{debug, trace};
 
@@ -67,7 +65,7 @@ impl Buffer {
                 called: function.into(),
                 paren_spans: None,
                 args,
-                kwargs: HashMap::default(),
+                kwargs: vec![],
             });
             trace!(?ele);
             let buffer_window = BufferWindow {
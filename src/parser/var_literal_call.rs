@@ -1,22 +1,20 @@
-use std::collections::HashMap;
-
-use itertools::Either;
-use num::BigInt;
+use itertools::{Either, Itertools};
 use tracing::{debug, trace};
 
 use crate::{
-    ast::{Ast, AstData, Call, Ident, Literal, Member},
+    ast::{Ast, AstData, Call, Ident, Index, Literal, Member, UnaryOpr, Unwrap},
     errors::{ToZResult, ZError, ZResult},
+    lexer::number_value,
     parser::buffer::{Buffer, BufferWindow},
     types::{
-        position::GetSpan,
-        token::{Token, TokenType},
+        position::{GetSpan, Span},
+        token::{OprType, Token, TokenType},
         value::Value,
     },
 };
 
 impl Buffer {
-    fn parse_str_literal(s: &str) -> ZResult<String> {
+    pub fn parse_str_literal(s: &str) -> ZResult<String> {
         let mut out = String::new();
         let mut chars = s.chars();
         while let Some(c) = chars.next() {
@@ -29,6 +27,20 @@ impl Buffer {
                 'n' => '\n',
                 't' => '\t',
                 'r' => '\r',
+                '0' => '\0',
+                // `\u{8ac3}`
+                'u' => {
+                    let escape = chars
+                        .by_ref()
+                        .skip_while(|c| *c == '{')
+                        .take_while(|c| *c != '}')
+                        .collect::<String>();
+                    u32::from_str_radix(&escape, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| ZError::p036(&escape))?
+                }
+                // the lexer has already rejected other escapes, so this is `"`, `{` or `}`
                 n => n,
             });
         }
@@ -43,6 +55,93 @@ impl Buffer {
             name_span: Some(token.span.to_owned()),
         })
     }
+    fn parse_literal(token: &Token) -> ZResult<Value> {
+        Ok(match token.ty {
+            Some(TokenType::LiteralMisc) => match &*token.value {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "unit" => Value::Unit,
+                "null" => Value::Opt(None),
+                "inf" => Value::F64(f64::INFINITY),
+                _ => unreachable!("{}", token.value),
+            },
+            Some(TokenType::LiteralNumber) => number_value(&token.value)?,
+            Some(TokenType::LiteralString) => {
+                Value::Str(if let Some(raw) = token.value.strip_prefix('r') {
+                    // no escapes in a raw string
+                    raw.get(1..raw.len() - 1).z()?.to_owned()
+                } else {
+                    let str = token.value.get(1..token.value.len() - 1).z()?;
+                    Self::parse_str_literal(str)?
+                })
+            }
+            Some(TokenType::LiteralChar) => {
+                let literal = token.value.get(2..token.value.len() - 1).z()?;
+                let str = Self::parse_str_literal(literal)?;
+                let mut chars = str.chars();
+                // `c"8ac3"` is a hex codepoint, `c"a"` is the character itself
+                Value::Char(match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => u32::from_str_radix(&str, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| ZError::p034(&token.value).with_span(token))?,
+                })
+            }
+            _ty => unreachable!("{_ty:?}"),
+        })
+    }
+    /// Parses a call argument, which is a keyword argument if it is of the form `key: value`
+    fn parse_arg(&mut self) -> ZResult<Either<Ast, (Ast, Ast)>> {
+        // the colon of a keyword argument is the first one outside of brackets
+        let mut bracket_level = 0usize;
+        let colon = self.content.iter().position(|ele| {
+            let Either::Right(token) = ele else {
+                return false;
+            };
+            match token.ty {
+                Some(
+                    TokenType::OpenParen | TokenType::OpenSquareParen | TokenType::OpenCurlyParen,
+                ) => bracket_level += 1,
+                Some(
+                    TokenType::CloseParen
+                    | TokenType::CloseSquareParen
+                    | TokenType::CloseCurlyParen,
+                ) => bracket_level = bracket_level.saturating_sub(1),
+                Some(TokenType::Colon) => return bracket_level == 0,
+                _ => (),
+            }
+            false
+        });
+        if let Some(colon @ 1..) = colon {
+            let key = self.window(0..colon).with_as_buffer(&Self::parse_as_expr)?;
+            let value = self
+                .window(colon + 1..self.content.len())
+                .with_as_buffer(&Self::parse_as_expr)?;
+            return Ok(Either::Right((key, value)));
+        }
+        Ok(Either::Left(self.parse_as_expr()?))
+    }
+    fn parse_index(&mut self, parent: Ast, open_bracket_span: Span) -> ZResult<Ast> {
+        let mut contents = self.get_split_between(
+            TokenType::OpenSquareParen,
+            TokenType::CloseSquareParen,
+            TokenType::Comma,
+        )?;
+        let args = contents.with_as_buffers(&|f| {
+            let ele = f.parse_as_expr()?;
+            Ok(ele)
+        })?;
+        let close_bracket_span = self
+            .this()
+            .and_then(|e| e.span())
+            .unwrap_or_else(|| unreachable!());
+        Ok(Ast::Index(Index {
+            parent: parent.into(),
+            bracket_spans: Some((open_bracket_span, close_bracket_span)),
+            args,
+        }))
+    }
     #[tracing::instrument(skip_all)]
     pub fn parse_var_literal_call(&mut self) -> ZResult<()> {
         self.reset_cursor();
@@ -109,54 +208,57 @@ impl Buffer {
                     trace!(catcher = ?catcher.as_ref().unwrap_or_else(|| unreachable!()).0);
                 }
                 Some(
-                    TokenType::LiteralNumber | TokenType::LiteralMisc | TokenType::LiteralString,
+                    TokenType::LiteralNumber
+                    | TokenType::LiteralMisc
+                    | TokenType::LiteralString
+                    | TokenType::LiteralChar,
                 ) => {
                     clear_catcher(self, &mut catcher, false);
                     catcher = Some((
                         Ast::Literal(Literal {
+                            content: Self::parse_literal(&selected)?,
                             span: Some(selected.span),
-                            content: match selected.ty {
-                                Some(TokenType::LiteralMisc) => match &*selected.value {
-                                    "true" => Value::Bool(true),
-                                    "false" => Value::Bool(false),
-                                    "unit" => Value::Unit,
-                                    "inf" => Value::F64(f64::INFINITY),
-                                    _ => unreachable!("{}", selected.value),
-                                },
-                                Some(TokenType::LiteralNumber) => {
-                                    if selected.value.contains('.') {
-                                        Value::F64(
-                                            selected
-                                                .value
-                                                .parse()
-                                                .unwrap_or_else(|_| unreachable!()),
-                                        )
-                                        // TODO Decimal
-                                    } else if let Ok(val) = selected.value.parse::<i32>() {
-                                        Value::I32(val)
-                                    } else if let Ok(val) = selected.value.parse::<i64>() {
-                                        Value::I64(val)
-                                    } else if let Ok(val) = selected.value.parse::<i128>() {
-                                        Value::I128(val)
-                                    } else if let Ok(val) = selected.value.parse::<u128>() {
-                                        Value::U128(val)
-                                    } else if let Ok(val) = selected.value.parse::<BigInt>() {
-                                        Value::Ibig(val)
-                                    } else {
-                                        unreachable!()
-                                    }
-                                }
-                                Some(TokenType::LiteralString) => Value::Str({
-                                    let str = &selected.value[1..selected.value.len() - 1];
-                                    Self::parse_str_literal(str)?
-                                }),
-                                _ty => unreachable!("{_ty:?}"),
-                            },
                         }),
                         self.cursor,
                     ));
                     trace!(catcher = ?catcher.as_ref().unwrap_or_else(|| unreachable!()).0);
                 }
+                Some(TokenType::LiteralFormatString) => {
+                    clear_catcher(self, &mut catcher, false);
+                    catcher = Some((Self::parse_format_string(&selected)?, self.cursor));
+                    trace!(catcher = ?catcher.as_ref().unwrap_or_else(|| unreachable!()).0);
+                }
+                Some(TokenType::PostfixOpr(opr_ty @ (OprType::Unwrap | OprType::Propagate))) => {
+                    debug!(pos = ?selected.span, "Parsing {opr_ty:?} operator");
+                    let Some((catcher, _)) = &mut catcher else {
+                        return Err(ZError::p032().with_span(&selected));
+                    };
+                    *catcher = Ast::Unwrap(Unwrap {
+                        operand: catcher.to_owned().into(),
+                        opr_span: Some(selected.span),
+                        propagate: opr_ty == OprType::Propagate,
+                    });
+                    trace!(?catcher);
+                }
+                Some(TokenType::PostfixOpr(opr_ty @ (OprType::Inc | OprType::Dec))) => {
+                    debug!(pos = ?selected.span, "Parsing {opr_ty:?} operator");
+                    let Some((catcher, _)) = &mut catcher else {
+                        return Err(ZError::p032().with_span(&selected));
+                    };
+                    *catcher = Ast::UnaryOpr(UnaryOpr {
+                        ty: opr_ty,
+                        opr_span: Some(selected.span),
+                        operand: catcher.to_owned().into(),
+                    });
+                    trace!(?catcher);
+                }
+                Some(TokenType::OpenSquareParen) => {
+                    if let Some((catcher, _)) = &mut catcher {
+                        debug!(pos = ?selected.span, "Parsing index");
+                        *catcher = self.parse_index(catcher.to_owned(), selected.span)?;
+                        trace!(?catcher);
+                    }
+                }
                 Some(TokenType::CloseParen) => return Err(ZError::p023().with_span(&selected)),
                 Some(TokenType::OpenParen) => {
                     let open_paren_span = selected.span;
@@ -172,10 +274,10 @@ impl Buffer {
                         TokenType::CloseParen,
                         TokenType::Comma,
                     )?;
-                    let args = contents.with_as_buffers(&|f| {
-                        let ele = f.parse_as_expr()?;
-                        Ok(ele)
-                    })?;
+                    let (args, kwargs) = contents
+                        .with_as_buffers(&Self::parse_arg)?
+                        .into_iter()
+                        .partition_map(|arg| arg);
                     let close_paren_span = self
                         .this()
                         .and_then(|e| e.span())
@@ -184,7 +286,7 @@ impl Buffer {
                         called: catcher.to_owned().into(),
                         paren_spans: Some((open_paren_span, close_paren_span)),
                         args,
-                        kwargs: HashMap::default(),
+                        kwargs,
                     });
                     trace!(?catcher);
                 }
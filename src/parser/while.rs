@@ -0,0 +1,100 @@
+use itertools::Either;
+use tracing::{debug, trace};
+
+use crate::{
+    ast::{Ast, While},
+    errors::{ZError, ZResult},
+    parser::buffer::{Buffer, BufferWindow},
+    types::{
+        position::GetSpan,
+        token::{Keyword, Token, TokenType},
+    },
+};
+
+impl Buffer {
+    #[tracing::instrument(skip_all)]
+    pub fn parse_while(&mut self) -> ZResult<()> {
+        self.reset_cursor();
+        while let Some(selected) = self.next() {
+            let Either::Right(Token {
+                ty: Some(TokenType::Keyword(kwd @ (Keyword::While | Keyword::Do))),
+                ..
+            }) = selected else {
+                continue;
+            };
+            let start = self.cursor;
+            let first_kwd_span = selected.span();
+            let label = self.label()?;
+
+            let (ele, end) = if kwd == Keyword::Do {
+                debug!(pos = ?first_kwd_span, "Parsing do-while");
+                let selected = self.next_or_err()?;
+                let Either::Left(Ast::Block(content)) = selected else {
+                    return Err(ZError::p025().with_span(selected));
+                };
+                let selected = self.next_or_err()?;
+                let Either::Right(Token {
+                    ty: Some(TokenType::Keyword(Keyword::While)),
+                    span: kwd_span,
+                    ..
+                }) = selected else {
+                    return Err(ZError::p026().with_span(selected));
+                };
+                self.next_or_err()?;
+                let condition = self.rest_incl_curr().with_as_buffer(&Self::parse_as_expr)?;
+                (
+                    Ast::While(While {
+                        is_do_while: true,
+                        label,
+                        kwd_span: Some(kwd_span),
+                        do_kwd_span: first_kwd_span,
+                        condition: condition.into(),
+                        content,
+                        init: None,
+                    }),
+                    self.content.len(),
+                )
+            } else {
+                debug!(pos = ?first_kwd_span, "Parsing while");
+                let selected = self.next_or_err()?;
+                let cond_start = self.cursor;
+                let condition = if matches!(selected, Either::Left(Ast::Block(_)))
+                    && matches!(self.peek(), Some(Either::Left(Ast::Block(_))))
+                {
+                    debug!(pos = ?selected.span(), "Detected condition expr in {{}}");
+                    selected.left().unwrap_or_else(|| unreachable!())
+                } else {
+                    debug!(pos = ?selected.span(), "Detected condition expr not in {{}}");
+                    while !matches!(self.peek(), Some(Either::Left(Ast::Block(_))) | None) {
+                        self.next();
+                    }
+                    self.window(cond_start..self.next_cursor_pos())
+                        .with_as_buffer(&Self::parse_as_expr)?
+                };
+                let selected = self.next_or_err()?;
+                let Either::Left(Ast::Block(content)) = selected else {
+                    return Err(ZError::p018().with_span(selected));
+                };
+                (
+                    Ast::While(While {
+                        is_do_while: false,
+                        label,
+                        kwd_span: first_kwd_span,
+                        do_kwd_span: None,
+                        condition: condition.into(),
+                        content,
+                        init: None,
+                    }),
+                    self.next_cursor_pos(),
+                )
+            };
+            trace!(?ele);
+            let buffer_window = BufferWindow {
+                slice: vec![Either::Left(ele)],
+                range: start..end,
+            };
+            self.splice_buffer(buffer_window);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn arr_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising arr");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Arr(x[1..].to_vec().into()))),
+        ZError::i001,
+        vec![],
+        Some(RestParam::Each(&ARR_ITEM_T)),
+        &ARR_SELF_T,
+    );
+    concat(&mut h, &ARR_SELF_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &ARR_SELF_T,
+        &ARR_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &ARR_SELF_T,
+        &ARR_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "len",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::I32(x[0].as_arr()?.len().try_into().ok()?))),
+        &ARR_SELF_T,
+        &I32_T,
+    );
+    binary_with_err(
+        &mut h,
+        "_index",
+        Arc::new(|x: &mut Vec<Value>| {
+            let i = usize::try_from(get_param::<i32>(x, 1)?).ok()?;
+            x[0].as_arr()?.get(i).cloned()
+        }),
+        ZError::i006,
+        &ARR_SELF_T,
+        &I32_T,
+        &ARR_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "_iter",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Iter(x[0].as_arr()?.as_slice().into(), 0))),
+        &ARR_SELF_T,
+        &ARR_ITER_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&ARR_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *ARR_T_VAL => x[0].to_owned(),
+            p if p == *VEC_T_VAL => Value::Vec(x[0].as_arr()?.to_owned()),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &ARR_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("arr")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![
+            ("T".into(), Arc::clone(&TYPE_T)),
+            ("n".into(), Arc::clone(&USIZE_T)),
+        ],
+    }
+}
+
+pub static ARR_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(arr_t().into()));
+pub static ARR_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(arr_t().into()));
+static ARR_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static ARR_ITER_T: Lazy<Arc<Type>> = Lazy::new(|| generic_iter(Arc::clone(&ARR_ITEM_T)));
+// the length is left unbound as `unit`, to be substituted like a type parameter
+static ARR_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| {
+    Type::generic(
+        &ARR_T,
+        vec![
+            (
+                "T".into(),
+                Either::Right(Either::Right(Arc::clone(&ARR_ITEM_T))),
+            ),
+            ("n".into(), Either::Left(Value::Unit)),
+        ],
+    )
+});
+
+#[must_use]
+pub fn generic_arr(item: Arc<Type>, len: usize) -> Arc<Type> {
+    Type::generic(
+        &ARR_T,
+        vec![
+            ("T".into(), Either::Right(Either::Right(item))),
+            ("n".into(), Either::Left(Value::Usize(len))),
+        ],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, binary_with_err, concat, ctor, get_param, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -5,7 +5,7 @@ use once_cell::sync::Lazy;
 use crate::{
     ast::Ident,
     primitives::{
-        utils::{comp_opr_default, concat, get_param, type_cast},
+        utils::{comp_opr_default, concat, get_param, hash, type_cast, unary},
         *,
     },
     types::{
@@ -31,9 +31,17 @@ fn bool_t() -> BuiltinType {
     trace!("Initialising bool");
     h.insert("_default", Value::Bool(false));
     concat(&mut h, &BOOL_T);
+    hash(&mut h, &BOOL_T);
     comp_opr_default::<bool>(&mut h, &BOOL_T);
+    unary(
+        &mut h,
+        "_not",
+        Arc::new(|x: &mut Vec<Value>| Some((!get_param::<bool>(x, 0)?).into())),
+        &BOOL_T,
+        &BOOL_T,
+    );
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&BOOL_T_VAL)),
             p if p == *STR_T_VAL => Value::Str(get_param::<bool>(x, 0)?.to_string()),
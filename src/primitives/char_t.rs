@@ -0,0 +1,48 @@
+use std::{collections::HashMap, sync::Arc};
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{
+    ast::Ident,
+    primitives::{
+        utils::{comp_opr_default, concat, get_param, hash, type_cast},
+        *,
+    },
+    types::{
+        r#type::{BuiltinType, ValueType},
+        value::Value,
+    },
+    Type,
+};
+
+#[allow(clippy::cognitive_complexity)]
+fn char_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising char");
+    h.insert("_default", Value::Char('\0'));
+    concat(&mut h, &CHAR_T);
+    hash(&mut h, &CHAR_T);
+    comp_opr_default::<char>(&mut h, &CHAR_T);
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&CHAR_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(get_param::<char>(x, 0)?.to_string()),
+            p if p == *CHAR_T_VAL => x[0].to_owned(),
+            p if p == *U32_T_VAL => Value::U32(get_param::<char>(x, 0)?.into()),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &CHAR_T);
+
+    BuiltinType {
+        name: Some(Ident::new("char")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![],
+    }
+}
+
+pub static CHAR_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(char_t().into()));
+pub static CHAR_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(char_t().into()));
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use num::Complex;
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn cpx_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising cpx");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| Some(Complex::new(x[1].to_owned(), x[2].to_owned()).into())),
+        ZError::i001,
+        vec![&CPX_ITEM_T, &CPX_ITEM_T],
+        None,
+        &CPX_SELF_T,
+    );
+    h.insert(
+        "_default",
+        Value::Cpx(Box::new(Complex::new(Value::I32(0), Value::I32(0)))),
+    );
+    concat(&mut h, &CPX_SELF_T);
+    hash(&mut h, &CPX_SELF_T);
+    unary(
+        &mut h,
+        "_un_add",
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
+        &CPX_SELF_T,
+        &CPX_SELF_T,
+    );
+    unary(
+        &mut h,
+        "_un_sub",
+        Arc::new(|x: &mut Vec<Value>| {
+            let cpx = get_param::<Complex<Value>>(x, 0)?;
+            Some(
+                Complex::new(
+                    call_method("_un_sub", vec![cpx.re])?,
+                    call_method("_un_sub", vec![cpx.im])?,
+                )
+                .into(),
+            )
+        }),
+        &CPX_SELF_T,
+        &CPX_SELF_T,
+    );
+    arith_opr_cpx_default(&mut h, &CPX_SELF_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &CPX_SELF_T,
+        &CPX_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &CPX_SELF_T,
+        &CPX_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "re",
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<Complex<Value>>(x, 0)?.re)),
+        &CPX_SELF_T,
+        &CPX_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "im",
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<Complex<Value>>(x, 0)?.im)),
+        &CPX_SELF_T,
+        &CPX_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "conj",
+        Arc::new(|x: &mut Vec<Value>| {
+            let cpx = get_param::<Complex<Value>>(x, 0)?;
+            Some(Complex::new(cpx.re, call_method("_un_sub", vec![cpx.im])?).into())
+        }),
+        &CPX_SELF_T,
+        &CPX_SELF_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&CPX_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *CPX_T_VAL => x[0].to_owned(),
+            // only a `cpx` with no imaginary part can be cast to a real number
+            p => {
+                let cpx = get_param::<Complex<Value>>(x, 0)?;
+                if !is_zero(&cpx.im)? {
+                    return None;
+                }
+                call_method("_typecast", vec![cpx.re, Value::Type(p)])?
+            }
+        })
+    });
+    type_cast(&mut h, typecast, &CPX_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("cpx")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&CPX_ITEM_BOUND_T))],
+    }
+}
+
+pub static CPX_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(cpx_t().into()));
+pub static CPX_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(cpx_t().into()));
+static CPX_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static CPX_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_cpx(Arc::clone(&CPX_ITEM_T)));
+
+/// The types that the parts of a `cpx` can have
+static CPX_ITEM_BOUND_T: Lazy<Arc<Type>> = Lazy::new(|| {
+    Type::union(
+        [
+            &I8_T, &I16_T, &I32_T, &I64_T, &I128_T, &ISIZE_T, &IBIG_T, &U8_T, &U16_T, &U32_T,
+            &U64_T, &U128_T, &USIZE_T, &UBIG_T, &F16_T, &F32_T, &F64_T,
+        ]
+        .map(|ty| Arc::clone(ty)),
+    )
+});
+#[must_use]
+pub fn generic_cpx(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &CPX_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{
+        arith_opr_cpx_default, binary, call_method, concat, ctor, get_param, hash, type_cast, unary,
+    },
+    types::r#type::{BuiltinType, ValueType},
+};
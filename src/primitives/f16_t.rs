@@ -36,21 +36,21 @@ fn f16_t() -> BuiltinType {
     unary(
         &mut h,
         "_un_add",
-        Arc::new(|x: &Vec<Value>| Some(x[0].to_owned())),
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
         &F16_T,
         &F16_T,
     );
     unary(
         &mut h,
         "_un_sub",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<f16>(x, 0)?.neg().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<f16>(x, 0)?.neg().into())),
         &F16_T,
         &F16_T,
     );
     unary(
         &mut h,
         "_un_sub",
-        Arc::new(|x: &Vec<Value>| {
+        Arc::new(|x: &mut Vec<Value>| {
             Some(
                 (get_param::<f16>(x, 0)?.eq(&f16::ZERO)
                     || get_param::<f16>(x, 0)?.eq(&f16::NEG_ZERO))
@@ -63,7 +63,7 @@ fn f16_t() -> BuiltinType {
     arith_opr_float_default::<f16>(&mut h, &F16_T);
     comp_opr_default::<f16>(&mut h, &F16_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&F16_T_VAL)),
             p if p == *STR_T_VAL => typecast_float!(f16 => str, x),
@@ -87,6 +87,7 @@ fn f16_t() -> BuiltinType {
             p if p == *F16_T_VAL => x[0].to_owned(),
             p if p == *F32_T_VAL => Value::F32(get_param::<f16>(x, 0)?.to_f32()),
             p if p == *F64_T_VAL => Value::F64(get_param::<f16>(x, 0)?.to_f64()),
+            p if p == *CPX_T_VAL => typecast_float!(f16 => cpx, x),
             _ => return None,
         })
     });
@@ -34,7 +34,7 @@ fn f32_t() -> BuiltinType {
     arith_opr_float_default::<f32>(&mut h, &F32_T);
     comp_opr_default::<f32>(&mut h, &F32_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&F32_T_VAL)),
             p if p == *STR_T_VAL => typecast_float!(f32 => str, x),
@@ -56,6 +56,7 @@ fn f32_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_float!(f32 => f16, x),
             p if p == *F32_T_VAL => x[0].to_owned(),
             p if p == *F64_T_VAL => typecast_float!(f32 => F64 to_f64, x),
+            p if p == *CPX_T_VAL => typecast_float!(f32 => cpx, x),
             _ => return None,
         })
     });
@@ -35,7 +35,7 @@ fn f64_t() -> BuiltinType {
     arith_opr_float_default::<f64>(&mut h, &F64_T);
     comp_opr_default::<f64>(&mut h, &F64_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&F64_T_VAL)),
             p if p == *STR_T_VAL => typecast_float!(f64 => str, x),
@@ -57,6 +57,7 @@ fn f64_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_float!(f64 => f16, x),
             p if p == *F32_T_VAL => typecast_float!(f64 => F32 to_f32, x),
             p if p == *F64_T_VAL => x[0].to_owned(),
+            p if p == *CPX_T_VAL => typecast_float!(f64 => cpx, x),
             _ => return None,
         })
     });
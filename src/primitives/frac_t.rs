@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+
+/// A `frac`, kept in lowest terms with a positive denominator
+#[derive(Clone, PartialEq, Debug)]
+pub struct Fraction {
+    pub num: Value,
+    pub den: Value,
+}
+
+/// Whether a number equals the `_default` of its type
+pub fn is_zero(v: &Value) -> Option<bool> {
+    key_eq(v, v.value_ty().namespace().get("_default")?)
+}
+
+/// Builds a `frac` in lowest terms, or `None` if the denominator is zero
+pub fn frac_new(num: &Value, den: &Value) -> Option<Fraction> {
+    if is_zero(den)? {
+        return None;
+    }
+    let (mut a, mut b) = (num.to_owned(), den.to_owned());
+    while !is_zero(&b)? {
+        (a, b) = (b.to_owned(), component_opr("_rem", &a, &b)?);
+    }
+    let (num, den) = (
+        component_opr("_div", num, &a)?,
+        component_opr("_div", den, &a)?,
+    );
+    let zero = den.value_ty().namespace().get("_default")?.to_owned();
+    Some(if component_opr("_lt", &den, &zero)?.into_bool().ok()? {
+        Fraction {
+            num: call_method("_un_sub", vec![num])?,
+            den: call_method("_un_sub", vec![den])?,
+        }
+    } else {
+        Fraction { num, den }
+    })
+}
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn frac_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising frac");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| Some(frac_new(&x[1], &x[2])?.into())),
+        |x| ZError::i003(&x[1]),
+        vec![&FRAC_ITEM_T, &FRAC_ITEM_T],
+        None,
+        &FRAC_SELF_T,
+    );
+    h.insert(
+        "_default",
+        Value::Frac(Box::new(Fraction {
+            num: Value::I32(0),
+            den: Value::I32(1),
+        })),
+    );
+    concat(&mut h, &FRAC_SELF_T);
+    hash(&mut h, &FRAC_SELF_T);
+    unary(
+        &mut h,
+        "_un_add",
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
+        &FRAC_SELF_T,
+        &FRAC_SELF_T,
+    );
+    unary(
+        &mut h,
+        "_un_sub",
+        Arc::new(|x: &mut Vec<Value>| {
+            let frac = get_param::<Fraction>(x, 0)?;
+            Some(
+                Fraction {
+                    num: call_method("_un_sub", vec![frac.num])?,
+                    den: frac.den,
+                }
+                .into(),
+            )
+        }),
+        &FRAC_SELF_T,
+        &FRAC_SELF_T,
+    );
+    arith_opr_frac_default(&mut h, &FRAC_SELF_T);
+    comp_opr_frac_default(&mut h, &FRAC_SELF_T);
+    unary(
+        &mut h,
+        "num",
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<Fraction>(x, 0)?.num)),
+        &FRAC_SELF_T,
+        &FRAC_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "den",
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<Fraction>(x, 0)?.den)),
+        &FRAC_SELF_T,
+        &FRAC_ITEM_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&FRAC_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *FRAC_T_VAL => x[0].to_owned(),
+            p => {
+                let frac = get_param::<Fraction>(x, 0)?;
+                let num = call_method("_typecast", vec![frac.num, Value::Type(Arc::clone(&p))])?;
+                let den = call_method("_typecast", vec![frac.den, Value::Type(p)])?;
+                component_opr("_div", &num, &den)?
+            }
+        })
+    });
+    type_cast(&mut h, typecast, &FRAC_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("frac")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&FRAC_ITEM_BOUND_T))],
+    }
+}
+
+pub static FRAC_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(frac_t().into()));
+pub static FRAC_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(frac_t().into()));
+static FRAC_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static FRAC_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_frac(Arc::clone(&FRAC_ITEM_T)));
+
+/// The types that the numerator and denominator of a `frac` can have
+static FRAC_ITEM_BOUND_T: Lazy<Arc<Type>> = Lazy::new(|| {
+    Type::union(
+        [
+            &I8_T, &I16_T, &I32_T, &I64_T, &I128_T, &ISIZE_T, &IBIG_T, &U8_T, &U16_T, &U32_T,
+            &U64_T, &U128_T, &USIZE_T, &UBIG_T,
+        ]
+        .map(|ty| Arc::clone(ty)),
+    )
+});
+#[must_use]
+pub fn generic_frac(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &FRAC_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{
+        arith_opr_frac_default, call_method, comp_opr_frac_default, component_opr, concat, ctor,
+        get_param, hash, key_eq, type_cast, unary,
+    },
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -0,0 +1,225 @@
+use std::collections::{BTreeMap, HashMap};
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+
+/// The entries of a `hmap`, bucketed by the `_hash` of their keys
+pub type HmapEntries = BTreeMap<u64, Vec<(Value, Value)>>;
+
+fn get(map: &HmapEntries, key: &Value) -> Option<Option<Value>> {
+    Some(
+        map.get(&key_hash(key)?)
+            .and_then(|bucket| bucket.iter().find(|(k, _)| key_eq(k, key) == Some(true)))
+            .map(|(_, v)| v.to_owned()),
+    )
+}
+
+/// Inserts an entry, returning the value it replaced, or `None` if the key cannot be hashed
+pub fn hmap_insert(map: &mut HmapEntries, key: Value, value: Value) -> Option<Option<Value>> {
+    let bucket = map.entry(key_hash(&key)?).or_default();
+    if let Some((_, v)) = bucket
+        .iter_mut()
+        .find(|(k, _)| key_eq(k, &key) == Some(true))
+    {
+        return Some(Some(std::mem::replace(v, value)));
+    }
+    bucket.push((key, value));
+    Some(None)
+}
+
+fn remove(map: &mut HmapEntries, key: &Value) -> Option<Option<Value>> {
+    let hash = key_hash(key)?;
+    let Some(bucket) = map.get_mut(&hash) else {
+        return Some(None);
+    };
+    let Some(i) = bucket
+        .iter()
+        .position(|(k, _)| key_eq(k, key) == Some(true))
+    else {
+        return Some(None);
+    };
+    let (_, v) = bucket.remove(i);
+    if bucket.is_empty() {
+        map.remove(&hash);
+    }
+    Some(Some(v))
+}
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn hmap_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising hmap");
+    // `hmap(key: value, ...)` is passed its entries as `(key, value)` pairs
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| {
+            let mut entries = HmapEntries::new();
+            for entry in &x[1..] {
+                let [key, value] = <[Value; 2]>::try_from(entry.as_tup()?.to_vec()).ok()?;
+                hmap_insert(&mut entries, key, value)?;
+            }
+            Some(Value::Hmap(entries.into()))
+        }),
+        ZError::i001,
+        vec![],
+        Some(RestParam::Each(&HMAP_ENTRY_T)),
+        &HMAP_SELF_T,
+    );
+    h.insert("_default", Value::Hmap(BTreeMap::new().into()));
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &HMAP_SELF_T,
+        &HMAP_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &HMAP_SELF_T,
+        &HMAP_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "len",
+        Arc::new(|x: &mut Vec<Value>| {
+            let len = x[0].as_hmap()?.values().map(Vec::len).sum::<usize>();
+            Some(Value::I32(len.try_into().ok()?))
+        }),
+        &HMAP_SELF_T,
+        &I32_T,
+    );
+    binary(
+        &mut h,
+        "_index",
+        Arc::new(|x: &mut Vec<Value>| get(x[0].as_hmap()?, &x[1])?),
+        &HMAP_SELF_T,
+        &HMAP_KEY_T,
+        &HMAP_VALUE_T,
+    );
+    binary(
+        &mut h,
+        "get",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Opt(get(x[0].as_hmap()?, &x[1])?.map(Box::new)))),
+        &HMAP_SELF_T,
+        &HMAP_KEY_T,
+        &HMAP_VALUE_OPT_T,
+    );
+    binary(
+        &mut h,
+        "contains",
+        Arc::new(|x: &mut Vec<Value>| Some(get(x[0].as_hmap()?, &x[1])?.is_some().into())),
+        &HMAP_SELF_T,
+        &HMAP_KEY_T,
+        &BOOL_T,
+    );
+    in_place(
+        &mut h,
+        "insert",
+        Arc::new(|x: &mut Vec<Value>| {
+            let (key, value) = (x[1].to_owned(), x[2].to_owned());
+            let old = hmap_insert(x[0].as_hmap_mut()?, key, value)?;
+            Some(Value::Opt(old.map(Box::new)))
+        }),
+        vec![&HMAP_SELF_T, &HMAP_KEY_T, &HMAP_VALUE_T],
+        &HMAP_VALUE_OPT_T,
+    );
+    in_place(
+        &mut h,
+        "remove",
+        Arc::new(|x: &mut Vec<Value>| {
+            let key = x[1].to_owned();
+            let old = remove(x[0].as_hmap_mut()?, &key)?;
+            Some(Value::Opt(old.map(Box::new)))
+        }),
+        vec![&HMAP_SELF_T, &HMAP_KEY_T],
+        &HMAP_VALUE_OPT_T,
+    );
+    unary(
+        &mut h,
+        "keys",
+        Arc::new(|x: &mut Vec<Value>| {
+            let keys = x[0]
+                .as_hmap()?
+                .values()
+                .flatten()
+                .map(|(k, _)| k.to_owned());
+            Some(Value::Vec(keys.collect()))
+        }),
+        &HMAP_SELF_T,
+        &HMAP_KEY_VEC_T,
+    );
+    unary(
+        &mut h,
+        "values",
+        Arc::new(|x: &mut Vec<Value>| {
+            let values = x[0]
+                .as_hmap()?
+                .values()
+                .flatten()
+                .map(|(_, v)| v.to_owned());
+            Some(Value::Vec(values.collect()))
+        }),
+        &HMAP_SELF_T,
+        &HMAP_VALUE_VEC_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&HMAP_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *HMAP_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &HMAP_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("hmap")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![
+            ("K".into(), Arc::clone(&TYPE_T)),
+            ("V".into(), Arc::clone(&TYPE_T)),
+        ],
+    }
+}
+
+pub static HMAP_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(hmap_t().into()));
+pub static HMAP_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(hmap_t().into()));
+static HMAP_KEY_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("K".into())));
+static HMAP_VALUE_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("V".into())));
+static HMAP_VALUE_OPT_T: Lazy<Arc<Type>> = Lazy::new(|| generic_opt(Arc::clone(&HMAP_VALUE_T)));
+static HMAP_KEY_VEC_T: Lazy<Arc<Type>> = Lazy::new(|| generic_vec(Arc::clone(&HMAP_KEY_T)));
+static HMAP_VALUE_VEC_T: Lazy<Arc<Type>> = Lazy::new(|| generic_vec(Arc::clone(&HMAP_VALUE_T)));
+static HMAP_SELF_T: Lazy<Arc<Type>> =
+    Lazy::new(|| generic_hmap(Arc::clone(&HMAP_KEY_T), Arc::clone(&HMAP_VALUE_T)));
+
+static HMAP_ENTRY_T: Lazy<Arc<Type>> =
+    Lazy::new(|| generic_tup(vec![Arc::clone(&HMAP_KEY_T), Arc::clone(&HMAP_VALUE_T)]));
+#[must_use]
+pub fn generic_hmap(key: Arc<Type>, value: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &HMAP_T,
+        vec![
+            ("K".into(), Either::Right(Either::Right(key))),
+            ("V".into(), Either::Right(Either::Right(value))),
+        ],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, ctor, get_param, in_place, key_eq, key_hash, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
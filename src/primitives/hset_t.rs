@@ -0,0 +1,174 @@
+use std::collections::{BTreeMap, HashMap};
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+
+/// The items of a `hset`, bucketed by their `_hash`
+pub type HsetItems = BTreeMap<u64, Vec<Value>>;
+
+fn contains(set: &HsetItems, item: &Value) -> Option<bool> {
+    Some(
+        set.get(&key_hash(item)?)
+            .is_some_and(|bucket| bucket.iter().any(|i| key_eq(i, item) == Some(true))),
+    )
+}
+
+/// Inserts an item, returning whether it was not already present, or `None` if it cannot be hashed
+pub fn hset_insert(set: &mut HsetItems, item: Value) -> Option<bool> {
+    let bucket = set.entry(key_hash(&item)?).or_default();
+    if bucket.iter().any(|i| key_eq(i, &item) == Some(true)) {
+        return Some(false);
+    }
+    bucket.push(item);
+    Some(true)
+}
+
+fn remove(set: &mut HsetItems, item: &Value) -> Option<bool> {
+    let hash = key_hash(item)?;
+    let Some(bucket) = set.get_mut(&hash) else {
+        return Some(false);
+    };
+    let Some(i) = bucket.iter().position(|i| key_eq(i, item) == Some(true)) else {
+        return Some(false);
+    };
+    bucket.remove(i);
+    if bucket.is_empty() {
+        set.remove(&hash);
+    }
+    Some(true)
+}
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn hset_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising hset");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| {
+            let mut items = HsetItems::new();
+            for item in &x[1..] {
+                hset_insert(&mut items, item.to_owned())?;
+            }
+            Some(Value::Hset(items.into()))
+        }),
+        ZError::i001,
+        vec![],
+        Some(RestParam::Each(&HSET_ITEM_T)),
+        &HSET_SELF_T,
+    );
+    h.insert("_default", Value::Hset(BTreeMap::new().into()));
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &HSET_SELF_T,
+        &HSET_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &HSET_SELF_T,
+        &HSET_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "len",
+        Arc::new(|x: &mut Vec<Value>| {
+            let len = x[0].as_hset()?.values().map(Vec::len).sum::<usize>();
+            Some(Value::I32(len.try_into().ok()?))
+        }),
+        &HSET_SELF_T,
+        &I32_T,
+    );
+    binary(
+        &mut h,
+        "contains",
+        Arc::new(|x: &mut Vec<Value>| Some(contains(x[0].as_hset()?, &x[1])?.into())),
+        &HSET_SELF_T,
+        &HSET_ITEM_T,
+        &BOOL_T,
+    );
+    in_place(
+        &mut h,
+        "insert",
+        Arc::new(|x: &mut Vec<Value>| {
+            let item = x[1].to_owned();
+            Some(hset_insert(x[0].as_hset_mut()?, item)?.into())
+        }),
+        vec![&HSET_SELF_T, &HSET_ITEM_T],
+        &BOOL_T,
+    );
+    h.insert("_insert", h["insert"].to_owned());
+    in_place(
+        &mut h,
+        "remove",
+        Arc::new(|x: &mut Vec<Value>| {
+            let item = x[1].to_owned();
+            Some(remove(x[0].as_hset_mut()?, &item)?.into())
+        }),
+        vec![&HSET_SELF_T, &HSET_ITEM_T],
+        &BOOL_T,
+    );
+    h.insert("_extract", h["remove"].to_owned());
+    unary(
+        &mut h,
+        "values",
+        Arc::new(|x: &mut Vec<Value>| {
+            Some(Value::Vec(
+                x[0].as_hset()?.values().flatten().cloned().collect(),
+            ))
+        }),
+        &HSET_SELF_T,
+        &HSET_ITEM_VEC_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&HSET_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *HSET_T_VAL => x[0].to_owned(),
+            p if p == *VEC_T_VAL => {
+                Value::Vec(x[0].as_hset()?.values().flatten().cloned().collect())
+            }
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &HSET_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("hset")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+pub static HSET_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(hset_t().into()));
+pub static HSET_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(hset_t().into()));
+static HSET_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static HSET_ITEM_VEC_T: Lazy<Arc<Type>> = Lazy::new(|| generic_vec(Arc::clone(&HSET_ITEM_T)));
+static HSET_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_hset(Arc::clone(&HSET_ITEM_T)));
+
+#[must_use]
+pub fn generic_hset(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &HSET_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, ctor, get_param, in_place, key_eq, key_hash, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -11,11 +11,14 @@ fn i16_t() -> BuiltinType {
     trace!("Initialising i16");
     h.insert("_default", Value::I16(0));
     concat(&mut h, &I16_T);
+    hash(&mut h, &I16_T);
     unary_signed_default::<i16>(&mut h, &I16_T);
     arith_opr_default::<i16>(&mut h, &I16_T);
+    bit_opr_default::<i16>(&mut h, &I16_T);
     comp_opr_default::<i16>(&mut h, &I16_T);
+    range_opr(&mut h, &I16_T, &I16_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&I16_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(i16 => str, x),
@@ -37,6 +40,8 @@ fn i16_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(i16 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(i16 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(i16 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(i16 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(i16 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn i16_t() -> BuiltinType {
 
 pub static I16_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(i16_t().into()));
 pub static I16_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(i16_t().into()));
+static I16_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&I16_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_signed_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_signed_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
@@ -11,11 +11,14 @@ fn i32_t() -> BuiltinType {
     trace!("Initialising i32");
     h.insert("_default", Value::I32(0));
     concat(&mut h, &I32_T);
+    hash(&mut h, &I32_T);
     unary_signed_default::<i32>(&mut h, &I32_T);
     arith_opr_default::<i32>(&mut h, &I32_T);
+    bit_opr_default::<i32>(&mut h, &I32_T);
     comp_opr_default::<i32>(&mut h, &I32_T);
+    range_opr(&mut h, &I32_T, &I32_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&I32_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(i32 => str, x),
@@ -37,6 +40,8 @@ fn i32_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(i32 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(i32 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(i32 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(i32 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(i32 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn i32_t() -> BuiltinType {
 
 pub static I32_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(i32_t().into()));
 pub static I32_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(i32_t().into()));
+static I32_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&I32_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_signed_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_signed_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
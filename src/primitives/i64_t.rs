@@ -11,11 +11,14 @@ fn i64_t() -> BuiltinType {
     trace!("Initialising i64");
     h.insert("_default", Value::I64(0));
     concat(&mut h, &I64_T);
+    hash(&mut h, &I64_T);
     unary_signed_default::<i64>(&mut h, &I64_T);
     arith_opr_default::<i64>(&mut h, &I64_T);
+    bit_opr_default::<i64>(&mut h, &I64_T);
     comp_opr_default::<i64>(&mut h, &I64_T);
+    range_opr(&mut h, &I64_T, &I64_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&I64_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(i64 => str, x),
@@ -37,6 +40,8 @@ fn i64_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(i64 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(i64 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(i64 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(i64 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(i64 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn i64_t() -> BuiltinType {
 
 pub static I64_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(i64_t().into()));
 pub static I64_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(i64_t().into()));
+static I64_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&I64_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_signed_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_signed_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
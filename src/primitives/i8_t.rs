@@ -8,7 +8,8 @@ use crate::{
     ast::Ident,
     primitives::{
         utils::{
-            arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_signed_default,
+            arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash,
+            range_opr, type_cast, unary_signed_default,
         },
         *,
     },
@@ -26,11 +27,14 @@ fn i8_t() -> BuiltinType {
     trace!("Initialising i8");
     h.insert("_default", Value::I8(0));
     concat(&mut h, &I8_T);
+    hash(&mut h, &I8_T);
     unary_signed_default::<i8>(&mut h, &I8_T);
     arith_opr_default::<i8>(&mut h, &I8_T);
+    bit_opr_default::<i8>(&mut h, &I8_T);
     comp_opr_default::<i8>(&mut h, &I8_T);
+    range_opr(&mut h, &I8_T, &I8_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&I8_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(i8 => str, x),
@@ -52,6 +56,8 @@ fn i8_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(i8 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(i8 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(i8 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(i8 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(i8 => frac, x),
             _ => return None,
         })
     });
@@ -67,3 +73,4 @@ fn i8_t() -> BuiltinType {
 
 pub static I8_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(i8_t().into()));
 pub static I8_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(i8_t().into()));
+static I8_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&I8_T)));
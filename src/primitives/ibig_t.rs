@@ -13,32 +13,35 @@ fn ibig_t() -> BuiltinType {
     trace!("Initialising ibig");
     h.insert("_default", Value::Ibig(0.into()));
     concat(&mut h, &IBIG_T);
+    hash(&mut h, &IBIG_T);
     unary(
         &mut h,
         "_un_add",
-        Arc::new(|x: &Vec<Value>| Some(x[0].to_owned())),
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
         &IBIG_T,
         &IBIG_T,
     );
     unary(
         &mut h,
         "_un_sub",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<BigInt>(x, 0)?.neg().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<BigInt>(x, 0)?.neg().into())),
         &IBIG_T,
         &IBIG_T,
     );
     unary(
         &mut h,
         "_not",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<BigInt>(x, 0)?.is_zero().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<BigInt>(x, 0)?.is_zero().into())),
         &IBIG_T,
         &BOOL_T,
     );
     arith_opr_big_default::<BigInt>(&mut h, &IBIG_T);
-    arith_opr::<BigInt>(&mut h, "_rem", &std::ops::Rem::rem, &IBIG_T);
+    bit_compl::<BigInt>(&mut h, &IBIG_T);
+    bit_opr_big_default::<BigInt>(&mut h, &IBIG_T);
     comp_opr_default::<BigInt>(&mut h, &IBIG_T);
+    range_opr(&mut h, &IBIG_T, &IBIG_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&IBIG_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(BigInt => str, x),
@@ -60,6 +63,8 @@ fn ibig_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(big BigInt => f16, x),
             p if p == *F32_T_VAL => typecast_int!(big BigInt => f32, x),
             p if p == *F64_T_VAL => typecast_int!(big BigInt => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(BigInt => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(BigInt => frac, x),
             _ => return None,
         })
     });
@@ -75,6 +80,7 @@ fn ibig_t() -> BuiltinType {
 
 pub static IBIG_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(ibig_t().into()));
 pub static IBIG_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(ibig_t().into()));
+static IBIG_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&IBIG_T)));
 
 use std::sync::Arc;
 
@@ -83,7 +89,8 @@ use num::BigInt;
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr, arith_opr_big_default, comp_opr_default, concat, get_param, type_cast, unary,
+        arith_opr_big_default, bit_compl, bit_opr_big_default, comp_opr_default, concat, get_param,
+        hash, range_opr, type_cast, unary,
     },
     types::r#type::{BuiltinType, ValueType},
 };
@@ -12,11 +12,14 @@ fn isize_t() -> BuiltinType {
     trace!("Initialising isize");
     h.insert("_default", Value::Isize(0));
     concat(&mut h, &ISIZE_T);
+    hash(&mut h, &ISIZE_T);
     unary_signed_default::<isize>(&mut h, &ISIZE_T);
     arith_opr_default::<isize>(&mut h, &ISIZE_T);
+    bit_opr_default::<isize>(&mut h, &ISIZE_T);
     comp_opr_default::<isize>(&mut h, &ISIZE_T);
+    range_opr(&mut h, &ISIZE_T, &ISIZE_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&ISIZE_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(isize => str, x),
@@ -38,6 +41,8 @@ fn isize_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(isize => f16, x),
             p if p == *F32_T_VAL => typecast_int!(isize => f32, x),
             p if p == *F64_T_VAL => typecast_int!(isize => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(isize => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(isize => frac, x),
             _ => return None,
         })
     });
@@ -53,13 +58,15 @@ fn isize_t() -> BuiltinType {
 
 pub static ISIZE_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(isize_t().into()));
 pub static ISIZE_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(isize_t().into()));
+static ISIZE_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&ISIZE_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_signed_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_signed_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
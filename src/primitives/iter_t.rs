@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+/// `iter[T]`, which goes through the items of a `vec` or `arr` without copying them
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn iter_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising iter");
+    unary(
+        &mut h,
+        "_iter",
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
+        &ITER_SELF_T,
+        &ITER_SELF_T,
+    );
+    unary(
+        &mut h,
+        "_done",
+        Arc::new(|x: &mut Vec<Value>| {
+            let (items, i) = x[0].as_iter()?;
+            Some((*i >= items.len()).into())
+        }),
+        &ITER_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "_item",
+        Arc::new(|x: &mut Vec<Value>| {
+            let (items, i) = x[0].as_iter()?;
+            items.get(*i).cloned()
+        }),
+        &ITER_SELF_T,
+        &ITER_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "_next",
+        Arc::new(|x: &mut Vec<Value>| {
+            let (items, i) = x[0].as_iter()?;
+            Some(Value::Iter(Arc::clone(items), i + 1))
+        }),
+        &ITER_SELF_T,
+        &ITER_SELF_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&ITER_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *ITER_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &ITER_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("iter")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+pub static ITER_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(iter_t().into()));
+pub static ITER_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(iter_t().into()));
+static ITER_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static ITER_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_iter(Arc::clone(&ITER_ITEM_T)));
+
+#[must_use]
+pub fn generic_iter(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &ITER_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    primitives::utils::{get_param, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -1,7 +1,13 @@
+mod arr_t;
 mod bool_t;
+mod char_t;
+mod cpx_t;
 mod f16_t;
 mod f32_t;
 mod f64_t;
+mod frac_t;
+mod hmap_t;
+mod hset_t;
 mod i128_t;
 mod i16_t;
 mod i32_t;
@@ -9,8 +15,13 @@ mod i64_t;
 mod i8_t;
 mod ibig_t;
 mod isize_t;
+mod iter_t;
+mod opt_t;
 mod proc_t;
+mod range_t;
+mod res_t;
 mod str_t;
+mod tup_t;
 mod type_t;
 mod u128_t;
 mod u16_t;
@@ -18,16 +29,24 @@ mod u32_t;
 mod u64_t;
 mod u8_t;
 mod ubig_t;
+mod union_t;
 mod unit_t;
 mod usize_t;
 mod utils;
+mod vec_t;
 
 use std::collections::HashMap;
 
+pub use arr_t::{generic_arr, ARR_T, ARR_T_VAL};
 pub use bool_t::{BOOL_T, BOOL_T_VAL};
+pub use char_t::{CHAR_T, CHAR_T_VAL};
+pub use cpx_t::{generic_cpx, CPX_T, CPX_T_VAL};
 pub use f16_t::{F16_T, F16_T_VAL};
 pub use f32_t::{F32_T, F32_T_VAL};
 pub use f64_t::{F64_T, F64_T_VAL};
+pub use frac_t::{frac_new, generic_frac, is_zero, Fraction, FRAC_T, FRAC_T_VAL};
+pub use hmap_t::{generic_hmap, hmap_insert, HmapEntries, HMAP_T, HMAP_T_VAL};
+pub use hset_t::{generic_hset, hset_insert, HsetItems, HSET_T, HSET_T_VAL};
 pub use i128_t::{I128_T, I128_T_VAL};
 pub use i16_t::{I16_T, I16_T_VAL};
 pub use i32_t::{I32_T, I32_T_VAL};
@@ -35,8 +54,13 @@ pub use i64_t::{I64_T, I64_T_VAL};
 pub use i8_t::{I8_T, I8_T_VAL};
 pub use ibig_t::{IBIG_T, IBIG_T_VAL};
 pub use isize_t::{ISIZE_T, ISIZE_T_VAL};
-pub use proc_t::{generic_proc, LazyGenericProc, PROC_T, PROC_T_VAL};
+pub use iter_t::{generic_iter, ITER_T, ITER_T_VAL};
+pub use opt_t::{generic_opt, opt_item, OPT_T, OPT_T_VAL};
+pub use proc_t::{generic_proc, LazyGenericProc, RestParam, PROC_T, PROC_T_VAL};
+pub use range_t::{generic_range, RANGE_T, RANGE_T_VAL};
+pub use res_t::{generic_res, res_args, RES_T, RES_T_VAL};
 pub use str_t::{STR_T, STR_T_VAL};
+pub use tup_t::{generic_tup, tup_items, TUP_T, TUP_T_VAL};
 pub use type_t::{TYPE_T, TYPE_T_VAL};
 pub use u128_t::{U128_T, U128_T_VAL};
 pub use u16_t::{U16_T, U16_T_VAL};
@@ -44,8 +68,10 @@ pub use u32_t::{U32_T, U32_T_VAL};
 pub use u64_t::{U64_T, U64_T_VAL};
 pub use u8_t::{U8_T, U8_T_VAL};
 pub use ubig_t::{UBIG_T, UBIG_T_VAL};
+pub use union_t::{UNION_T, UNION_T_VAL};
 pub use unit_t::{UNIT_T, UNIT_T_VAL};
 pub use usize_t::{USIZE_T, USIZE_T_VAL};
+pub use vec_t::{generic_vec, VEC_T, VEC_T_VAL};
 
 pub static ANY_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Any));
 pub static ANY_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(ValueType::Any));
@@ -59,10 +85,18 @@ use crate::types::r#type::{Type, ValueType};
 
 pub static PRIMS: Lazy<HashMap<&'static str, Arc<Type>>> = Lazy::new(|| {
     hashmap! {
+        "arr" => Arc::clone(&ARR_T),
+        // `array(...)`, `set(...)` and `tu(...)` construct these too
+        "array" => Arc::clone(&ARR_T),
         "bool" => Arc::clone(&BOOL_T),
+        "char" => Arc::clone(&CHAR_T),
+        "cpx" => Arc::clone(&CPX_T),
         "f16" => Arc::clone(&F16_T),
         "f32" => Arc::clone(&F32_T),
         "f64" => Arc::clone(&F64_T),
+        "frac" => Arc::clone(&FRAC_T),
+        "hmap" => Arc::clone(&HMAP_T),
+        "hset" => Arc::clone(&HSET_T),
         "i128" => Arc::clone(&I128_T),
         "i16" => Arc::clone(&I16_T),
         "i32" => Arc::clone(&I32_T),
@@ -70,8 +104,15 @@ pub static PRIMS: Lazy<HashMap<&'static str, Arc<Type>>> = Lazy::new(|| {
         "i8" => Arc::clone(&I8_T),
         "ibig" => Arc::clone(&IBIG_T),
         "isize" => Arc::clone(&ISIZE_T),
+        "iter" => Arc::clone(&ITER_T),
+        "opt" => Arc::clone(&OPT_T),
         "proc" => Arc::clone(&PROC_T),
+        "range" => Arc::clone(&RANGE_T),
+        "res" => Arc::clone(&RES_T),
+        "set" => Arc::clone(&HSET_T),
         "str" => Arc::clone(&STR_T),
+        "tup" => Arc::clone(&TUP_T),
+        "tu" => Arc::clone(&TUP_T),
         "type" => Arc::clone(&TYPE_T),
         "u128" => Arc::clone(&U128_T),
         "u16" => Arc::clone(&U16_T),
@@ -81,16 +122,25 @@ pub static PRIMS: Lazy<HashMap<&'static str, Arc<Type>>> = Lazy::new(|| {
         "ubig" => Arc::clone(&UBIG_T),
         "unit" => Arc::clone(&UNIT_T),
         "usize" => Arc::clone(&USIZE_T),
-        "_any" => Arc::clone(&ANY_T)
+        "vec" => Arc::clone(&VEC_T),
+        "_any" => Arc::clone(&ANY_T),
+        "_U" => Arc::clone(&UNION_T)
     }
 });
 
 pub static PRIMS_VAL: Lazy<HashMap<&'static str, Arc<ValueType>>> = Lazy::new(|| {
     hashmap! {
+        "arr" => Arc::clone(&ARR_T_VAL),
+        "array" => Arc::clone(&ARR_T_VAL),
         "bool" => Arc::clone(&BOOL_T_VAL),
+        "char" => Arc::clone(&CHAR_T_VAL),
+        "cpx" => Arc::clone(&CPX_T_VAL),
         "f16" => Arc::clone(&F16_T_VAL),
         "f32" => Arc::clone(&F32_T_VAL),
         "f64" => Arc::clone(&F64_T_VAL),
+        "frac" => Arc::clone(&FRAC_T_VAL),
+        "hmap" => Arc::clone(&HMAP_T_VAL),
+        "hset" => Arc::clone(&HSET_T_VAL),
         "i128" => Arc::clone(&I128_T_VAL),
         "i16" => Arc::clone(&I16_T_VAL),
         "i32" => Arc::clone(&I32_T_VAL),
@@ -98,8 +148,15 @@ pub static PRIMS_VAL: Lazy<HashMap<&'static str, Arc<ValueType>>> = Lazy::new(||
         "i8" => Arc::clone(&I8_T_VAL),
         "ibig" => Arc::clone(&IBIG_T_VAL),
         "isize" => Arc::clone(&ISIZE_T_VAL),
+        "iter" => Arc::clone(&ITER_T_VAL),
+        "opt" => Arc::clone(&OPT_T_VAL),
         "proc" => Arc::clone(&PROC_T_VAL),
+        "range" => Arc::clone(&RANGE_T_VAL),
+        "res" => Arc::clone(&RES_T_VAL),
+        "set" => Arc::clone(&HSET_T_VAL),
         "str" => Arc::clone(&STR_T_VAL),
+        "tup" => Arc::clone(&TUP_T_VAL),
+        "tu" => Arc::clone(&TUP_T_VAL),
         "type" => Arc::clone(&TYPE_T_VAL),
         "u128" => Arc::clone(&U128_T_VAL),
         "u16" => Arc::clone(&U16_T_VAL),
@@ -109,6 +166,8 @@ pub static PRIMS_VAL: Lazy<HashMap<&'static str, Arc<ValueType>>> = Lazy::new(||
         "ubig" => Arc::clone(&UBIG_T_VAL),
         "unit" => Arc::clone(&UNIT_T_VAL),
         "usize" => Arc::clone(&USIZE_T_VAL),
-        "_any" => Arc::clone(&ANY_T_VAL)
+        "vec" => Arc::clone(&VEC_T_VAL),
+        "_any" => Arc::clone(&ANY_T_VAL),
+        "_U" => Arc::clone(&UNION_T_VAL)
     }
 });
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn opt_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising opt");
+    h.insert("_default", Value::Opt(None));
+    concat(&mut h, &OPT_SELF_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &OPT_SELF_T,
+        &OPT_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &OPT_SELF_T,
+        &OPT_SELF_T,
+        &BOOL_T,
+    );
+    // fails on `null`, which `!!` and `?` turn into an error and an early return respectively
+    unary_with_err(
+        &mut h,
+        "_unwrap",
+        Arc::new(|x: &mut Vec<Value>| Some(*x[0].as_opt()?.to_owned()?)),
+        ZError::i002,
+        &OPT_SELF_T,
+        &OPT_ITEM_T,
+    );
+    binary(
+        &mut h,
+        "_coalesce",
+        Arc::new(|x: &mut Vec<Value>| {
+            Some(
+                x[0].as_opt()?
+                    .to_owned()
+                    .map_or_else(|| x[1].to_owned(), |v| *v),
+            )
+        }),
+        &OPT_SELF_T,
+        &OPT_ITEM_T,
+        &OPT_ITEM_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&OPT_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *OPT_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &OPT_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("opt")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+pub static OPT_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(opt_t().into()));
+pub static OPT_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(opt_t().into()));
+static OPT_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static OPT_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_opt(Arc::clone(&OPT_ITEM_T)));
+
+#[must_use]
+pub fn generic_opt(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(&OPT_T, vec![("T".into(), Either::Right(Either::Right(item)))])
+}
+
+/// Returns `T` if `ty` is `opt[T]`
+#[must_use]
+pub fn opt_item(ty: &Type) -> Option<Arc<Type>> {
+    let Type::Generic { type_args, base } = ty else {
+        return None;
+    };
+    if !Arc::ptr_eq(base, &OPT_T) {
+        return None;
+    }
+    let Some((_, Either::Right(Either::Right(item)))) = type_args.first() else {
+        unreachable!()
+    };
+    Some(Arc::clone(item))
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, concat, get_param, type_cast, unary_with_err},
+    types::r#type::{BuiltinType, ValueType},
+};
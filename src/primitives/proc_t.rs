@@ -18,7 +18,7 @@ fn proc_t() -> BuiltinType {
     trace!("Initialising proc");
     concat(&mut h, &PROC_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&PROC_T_VAL)),
             p if p == *STR_T_VAL => Value::Str(get_param::<Proc>(x, 0)?.to_string()),
@@ -43,19 +43,47 @@ pub static PROC_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(proc_t().int
 
 #[must_use]
 pub fn generic_proc(args: Vec<Arc<Type>>, ret: Arc<Type>) -> Arc<Type> {
-    Arc::new(Type::Generic {
-        type_args: vec![
+    Type::generic(
+        &PROC_T,
+        vec![
+            ("A".into(), Either::Right(Either::Left(args))),
+            ("R".into(), Either::Right(Either::Right(ret))),
+        ],
+    )
+}
+
+/// Returns the type of a proc that takes `args`, then any number of arguments as `rest` says
+fn variadic_proc(args: Vec<Arc<Type>>, rest: TypeArg, ret: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &PROC_T,
+        vec![
             ("A".into(), Either::Right(Either::Left(args))),
             ("R".into(), Either::Right(Either::Right(ret))),
+            ("Rest".into(), rest),
         ],
-        base: Arc::clone(&PROC_T),
-    })
+    )
+}
+
+/// The arguments that a proc takes after its fixed ones, as many as it is called with
+#[derive(Clone, Copy)]
+pub enum RestParam {
+    /// Each of them has this type, so they all bind its type parameters the same way
+    Each(&'static Lazy<Arc<Type>>),
+    /// Their types are bound, as a list, to this type parameter
+    List(&'static Lazy<Arc<Type>>),
 }
 
+/// Works out the type that a builtin on types gives from the types it is called with
+pub type TypeFn = fn(&[Arc<Type>]) -> Arc<Type>;
+
 #[derive(Clone)]
 pub struct LazyGenericProc {
     pub args: Vec<&'static Lazy<Arc<Type>>>,
     pub ret: &'static Lazy<Arc<Type>>,
+    pub rest: Option<RestParam>,
+    /// For a builtin on types, like `T1 / T2`, so that the type checker knows the type it gives
+    /// when it is called on types known at compile time
+    pub type_fn: Option<TypeFn>,
     ty: OnceCell<Arc<Type>>,
 }
 
@@ -74,10 +102,20 @@ impl Deref for LazyGenericProc {
     type Target = Arc<Type>;
     fn deref(&self) -> &Self::Target {
         self.ty.get_or_init(|| {
-            generic_proc(
-                self.args.iter().map(|a| Arc::clone(a)).collect::<Vec<_>>(),
-                Arc::clone(self.ret),
-            )
+            let args = self.args.iter().map(|a| Arc::clone(a)).collect::<Vec<_>>();
+            match self.rest {
+                None => generic_proc(args, Arc::clone(self.ret)),
+                Some(RestParam::Each(ty)) => variadic_proc(
+                    args,
+                    Either::Right(Either::Right(Arc::clone(ty))),
+                    Arc::clone(self.ret),
+                ),
+                Some(RestParam::List(ty)) => variadic_proc(
+                    args,
+                    Either::Right(Either::Left(vec![Arc::clone(ty)])),
+                    Arc::clone(self.ret),
+                ),
+            }
         })
     }
 }
@@ -86,9 +124,34 @@ impl LazyGenericProc {
         Self {
             args,
             ret,
+            rest: None,
+            type_fn: None,
             ty: OnceCell::new(),
         }
     }
+    /// Like `new`, but the proc also takes any number of arguments after `args`
+    pub fn variadic(
+        args: Vec<&'static Lazy<Arc<Type>>>,
+        rest: RestParam,
+        ret: &'static Lazy<Arc<Type>>,
+    ) -> Self {
+        Self {
+            rest: Some(rest),
+            ..Self::new(args, ret)
+        }
+    }
+    /// Like `new`, but for a builtin on types, whose type when called on types is given by
+    /// `type_fn`
+    pub fn on_types(
+        args: Vec<&'static Lazy<Arc<Type>>>,
+        ret: &'static Lazy<Arc<Type>>,
+        type_fn: TypeFn,
+    ) -> Self {
+        Self {
+            type_fn: Some(type_fn),
+            ..Self::new(args, ret)
+        }
+    }
 }
 
 use std::sync::Arc;
@@ -98,5 +161,5 @@ use itertools::Either;
 use crate::{
     ast::Ident,
     primitives::utils::{concat, get_param, type_cast},
-    types::r#type::{BuiltinType, ValueType},
+    types::r#type::{BuiltinType, TypeArg, ValueType},
 };
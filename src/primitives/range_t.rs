@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn range_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising range");
+    h.insert(
+        "_default",
+        Value::Range(Box::new(Value::I32(0)..Value::I32(0))),
+    );
+    concat(&mut h, &RANGE_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0].as_range()? == x[1].as_range()?))),
+        &RANGE_SELF_T,
+        &RANGE_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0].as_range()? != x[1].as_range()?))),
+        &RANGE_SELF_T,
+        &RANGE_SELF_T,
+        &BOOL_T,
+    );
+
+    // a range is its own iterator
+    unary(
+        &mut h,
+        "_iter",
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
+        &RANGE_SELF_T,
+        &RANGE_SELF_T,
+    );
+    unary(
+        &mut h,
+        "_done",
+        Arc::new(|x: &mut Vec<Value>| {
+            let range = x[0].as_range()?;
+            Some((!is_before(&range.start, &range.end)?).into())
+        }),
+        &RANGE_SELF_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "_item",
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].as_range()?.start.to_owned())),
+        &RANGE_SELF_T,
+        &RANGE_ITEM_T,
+    );
+    unary(
+        &mut h,
+        "_next",
+        Arc::new(|x: &mut Vec<Value>| {
+            let range = x[0].as_range()?;
+            Some(Value::Range(Box::new(
+                succ(&range.start)?..range.end.to_owned(),
+            )))
+        }),
+        &RANGE_SELF_T,
+        &RANGE_SELF_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&RANGE_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *RANGE_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &RANGE_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("range")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+macro_rules! int_match {
+    ($value:expr, $n:ident => $f:expr; $($variant:ident),*) => {
+        match $value {
+            $(Value::$variant($n) => Some(Value::$variant($f)),)*
+            _ => None,
+        }
+    };
+    ($a:expr, $b:expr, $m:ident, $n:ident => $f:expr; $($variant:ident),*) => {
+        match ($a, $b) {
+            $((Value::$variant($m), Value::$variant($n)) => Some($f),)*
+            _ => None,
+        }
+    };
+}
+
+/// The integer after `value`, or `None` if it overflows
+fn succ(value: &Value) -> Option<Value> {
+    match value {
+        Value::Ibig(n) => Some(Value::Ibig(n + 1)),
+        Value::Ubig(n) => Some(Value::Ubig(n + 1u8)),
+        _ => int_match!(value, n => n.checked_add(1)?;
+            I8, I16, I32, I64, I128, Isize, U8, U16, U32, U64, U128, Usize),
+    }
+}
+
+/// Whether the integer `a` is less than the integer `b` of the same type
+fn is_before(a: &Value, b: &Value) -> Option<bool> {
+    int_match!(a, b, m, n => m < n;
+        I8, I16, I32, I64, I128, Isize, Ibig, U8, U16, U32, U64, U128, Usize, Ubig)
+}
+
+pub static RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(range_t().into()));
+pub static RANGE_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(range_t().into()));
+static RANGE_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static RANGE_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&RANGE_ITEM_T)));
+
+#[must_use]
+pub fn generic_range(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &RANGE_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    primitives::utils::{binary, concat, get_param, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
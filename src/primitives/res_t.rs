@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn res_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising res");
+    unary(
+        &mut h,
+        "ok",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Res(Ok(Box::new(x[0].to_owned()))))),
+        &RES_OK_T,
+        &RES_SELF_T,
+    );
+    unary(
+        &mut h,
+        "err",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Res(Err(Box::new(x[0].to_owned()))))),
+        &RES_ERR_T,
+        &RES_SELF_T,
+    );
+    concat(&mut h, &RES_SELF_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &RES_SELF_T,
+        &RES_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &RES_SELF_T,
+        &RES_SELF_T,
+        &BOOL_T,
+    );
+    // fails on an error, which `!!` and `!?` turn into an error and an early return respectively
+    unary_with_err(
+        &mut h,
+        "_unwrap",
+        Arc::new(|x: &mut Vec<Value>| Some(*x[0].as_res()?.to_owned().ok()?)),
+        ZError::i002,
+        &RES_SELF_T,
+        &RES_OK_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&RES_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *RES_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &RES_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("res")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![
+            ("T".into(), Arc::clone(&TYPE_T)),
+            ("R".into(), Arc::clone(&TYPE_T)),
+        ],
+    }
+}
+
+pub static RES_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(res_t().into()));
+pub static RES_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(res_t().into()));
+static RES_OK_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static RES_ERR_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("R".into())));
+static RES_SELF_T: Lazy<Arc<Type>> =
+    Lazy::new(|| generic_res(Arc::clone(&RES_OK_T), Arc::clone(&RES_ERR_T)));
+
+#[must_use]
+pub fn generic_res(ok: Arc<Type>, err: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &RES_T,
+        vec![
+            ("T".into(), Either::Right(Either::Right(ok))),
+            ("R".into(), Either::Right(Either::Right(err))),
+        ],
+    )
+}
+
+/// Returns `(T, R)` if `ty` is `res[T, R]`
+#[must_use]
+pub fn res_args(ty: &Type) -> Option<(Arc<Type>, Arc<Type>)> {
+    let Type::Generic { type_args, base } = ty else {
+        return None;
+    };
+    if !Arc::ptr_eq(base, &RES_T) {
+        return None;
+    }
+    let [(_, Either::Right(Either::Right(ok))), (_, Either::Right(Either::Right(err)))] =
+        &**type_args
+    else {
+        unreachable!()
+    };
+    Some((Arc::clone(ok), Arc::clone(err)))
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, concat, get_param, type_cast, unary, unary_with_err},
+    types::r#type::{BuiltinType, ValueType},
+};
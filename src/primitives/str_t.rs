@@ -16,12 +16,17 @@ fn str_t() -> BuiltinType {
     trace!("Initialising str");
     h.insert("_default", Value::Str(String::new()));
     concat(&mut h, &STR_T);
+    hash(&mut h, &STR_T);
+    comp_opr_default::<String>(&mut h, &STR_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&STR_T_VAL)),
             p if p == *STR_T_VAL => x[0].to_owned(),
             p if p == *BOOL_T_VAL => Value::Bool(get_param::<String>(x, 0)?.is_empty()),
+            p if p == *CHAR_T_VAL => {
+                Value::Char(get_param::<String>(x, 0)?.chars().exactly_one().ok()?)
+            }
             p if p == *I8_T_VAL => typecast_str_to_num!(I8, x),
             p if p == *I16_T_VAL => typecast_str_to_num!(I16, x),
             p if p == *I32_T_VAL => typecast_str_to_num!(I32, x),
@@ -43,10 +48,22 @@ fn str_t() -> BuiltinType {
         })
     });
     type_cast(&mut h, typecast, &STR_T);
+    binary_with_err(
+        &mut h,
+        "_index",
+        Arc::new(|x: &mut Vec<Value>| {
+            let i = usize::try_from(get_param::<i32>(x, 1)?).ok()?;
+            Some(Value::Char(get_param::<String>(x, 0)?.chars().nth(i)?))
+        }),
+        ZError::i006,
+        &STR_T,
+        &I32_T,
+        &CHAR_T,
+    );
     binary(
         &mut h,
         "_mul",
-        Arc::new(|x: &Vec<Value>| {
+        Arc::new(|x: &mut Vec<Value>| {
             Some(Value::Str(get_param::<String>(x, 0)?.repeat(get_param::<
                 usize,
             >(
@@ -71,8 +88,13 @@ pub static STR_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(str_t().into(
 
 use std::sync::Arc;
 
+use itertools::Itertools;
+
 use crate::{
     ast::Ident,
-    primitives::utils::{binary, concat, get_param, type_cast},
+    errors::ZError,
+    primitives::utils::{
+        binary, binary_with_err, comp_opr_default, concat, get_param, hash, type_cast,
+    },
     types::r#type::{BuiltinType, ValueType},
 };
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+/// `tup[T1, T2, ...]`. Its methods accept any tuple, so `_index` is typed by the type checker.
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn tup_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising tup");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Tup(x[1..].to_vec().into()))),
+        ZError::i001,
+        vec![],
+        Some(RestParam::List(&TUP_ITEM_T)),
+        &TUP_SELF_T,
+    );
+    concat(&mut h, &ANY_T);
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &ANY_T,
+        &ANY_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &ANY_T,
+        &ANY_T,
+        &BOOL_T,
+    );
+    unary(
+        &mut h,
+        "len",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::I32(x[0].as_tup()?.len().try_into().ok()?))),
+        &ANY_T,
+        &I32_T,
+    );
+    binary_with_err(
+        &mut h,
+        "_index",
+        Arc::new(|x: &mut Vec<Value>| {
+            let i = usize::try_from(get_param::<i32>(x, 1)?).ok()?;
+            x[0].as_tup()?.get(i).cloned()
+        }),
+        ZError::i006,
+        &ANY_T,
+        &I32_T,
+        &ANY_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&TUP_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *TUP_T_VAL => x[0].to_owned(),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &ANY_T);
+
+    BuiltinType {
+        name: Some(Ident::new("tup")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+pub static TUP_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(tup_t().into()));
+pub static TUP_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(tup_t().into()));
+
+static TUP_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static TUP_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_tup(vec![Arc::clone(&TUP_ITEM_T)]));
+#[must_use]
+pub fn generic_tup(items: Vec<Arc<Type>>) -> Arc<Type> {
+    Type::generic(
+        &TUP_T,
+        vec![("T".into(), Either::Right(Either::Left(items)))],
+    )
+}
+
+/// Returns the item types if `ty` is a `tup`
+#[must_use]
+pub fn tup_items(ty: &Type) -> Option<Vec<Arc<Type>>> {
+    let Type::Generic { type_args, base } = ty else {
+        return None;
+    };
+    if !Arc::ptr_eq(base, &TUP_T) {
+        return None;
+    }
+    let Some((_, Either::Right(Either::Left(items)))) = type_args.first() else {
+        unreachable!()
+    };
+    Some(items.to_owned())
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, binary_with_err, concat, ctor, get_param, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
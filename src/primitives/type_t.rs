@@ -13,7 +13,7 @@ fn type_t() -> BuiltinType {
     binary(
         &mut h,
         "_eq",
-        Arc::new(|x: &Vec<Value>| {
+        Arc::new(|x: &mut Vec<Value>| {
             Some(Value::Bool(
                 get_param::<Arc<ValueType>>(x, 0)? == get_param::<Arc<ValueType>>(x, 1)?,
             ))
@@ -25,7 +25,7 @@ fn type_t() -> BuiltinType {
     binary(
         &mut h,
         "_ne",
-        Arc::new(|x: &Vec<Value>| {
+        Arc::new(|x: &mut Vec<Value>| {
             Some(Value::Bool(
                 get_param::<Arc<ValueType>>(x, 0)? != get_param::<Arc<ValueType>>(x, 1)?,
             ))
@@ -34,8 +34,27 @@ fn type_t() -> BuiltinType {
         &TYPE_T,
         &BOOL_T,
     );
+    // `T1 / T2`, the union of the types
+    let div: Arc<BuiltinFunction> = Arc::new(|x: &mut Vec<Value>| {
+        Some(Value::Type(ValueType::union([
+            get_param::<Arc<ValueType>>(x, 0)?,
+            get_param::<Arc<ValueType>>(x, 1)?,
+        ])))
+    });
+    h.insert(
+        "_div",
+        Value::Proc(Proc::Builtin {
+            id: Arc::as_ptr(&div) as *const () as usize,
+            f: div,
+            ty: LazyGenericProc::on_types(vec![&TYPE_T, &TYPE_T], &TYPE_T, |tys| {
+                Type::union(tys.to_vec())
+            }),
+            updates_receiver: false,
+            fail_err: ZError::i001,
+        }),
+    );
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&TYPE_T_VAL)),
             p if p == *STR_T_VAL => Value::Str(get_param::<Arc<ValueType>>(x, 0)?.to_string()),
@@ -59,6 +78,10 @@ use std::sync::Arc;
 
 use crate::{
     ast::Ident,
+    errors::ZError,
     primitives::utils::{binary, concat, get_param, type_cast},
-    types::r#type::{BuiltinType, ValueType},
+    types::{
+        r#type::{BuiltinType, ValueType},
+        value::{BuiltinFunction, Proc},
+    },
 };
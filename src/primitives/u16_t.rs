@@ -11,11 +11,14 @@ fn u16_t() -> BuiltinType {
     trace!("Initialising u16");
     h.insert("_default", Value::U16(0));
     concat(&mut h, &U16_T);
+    hash(&mut h, &U16_T);
     unary_unsigned_default::<u16>(&mut h, &U16_T);
     arith_opr_default::<u16>(&mut h, &U16_T);
+    bit_opr_default::<u16>(&mut h, &U16_T);
     comp_opr_default::<u16>(&mut h, &U16_T);
+    range_opr(&mut h, &U16_T, &U16_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&U16_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(u16 => str, x),
@@ -37,6 +40,8 @@ fn u16_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(u16 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(u16 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(u16 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(u16 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(u16 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn u16_t() -> BuiltinType {
 
 pub static U16_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(u16_t().into()));
 pub static U16_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(u16_t().into()));
+static U16_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&U16_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_unsigned_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_unsigned_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
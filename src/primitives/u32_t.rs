@@ -11,15 +11,19 @@ fn u32_t() -> BuiltinType {
     trace!("Initialising u32");
     h.insert("_default", Value::U32(0));
     concat(&mut h, &U32_T);
+    hash(&mut h, &U32_T);
     unary_unsigned_default::<u32>(&mut h, &U32_T);
     arith_opr_default::<u32>(&mut h, &U32_T);
+    bit_opr_default::<u32>(&mut h, &U32_T);
     comp_opr_default::<u32>(&mut h, &U32_T);
+    range_opr(&mut h, &U32_T, &U32_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&U32_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(u32 => str, x),
             p if p == *BOOL_T_VAL => typecast_int!(u32 => bool, x),
+            p if p == *CHAR_T_VAL => Value::Char(char::from_u32(get_param::<u32>(x, 0)?)?),
             p if p == *I8_T_VAL => typecast_int!(u32 => I8, x),
             p if p == *I16_T_VAL => typecast_int!(u32 => I16, x),
             p if p == *I32_T_VAL => typecast_int!(u32 => I32, x),
@@ -37,6 +41,8 @@ fn u32_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(u32 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(u32 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(u32 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(u32 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(u32 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +58,15 @@ fn u32_t() -> BuiltinType {
 
 pub static U32_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(u32_t().into()));
 pub static U32_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(u32_t().into()));
+static U32_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&U32_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_unsigned_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_unsigned_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
@@ -11,11 +11,14 @@ fn u64_t() -> BuiltinType {
     trace!("Initialising u64");
     h.insert("_default", Value::U64(0));
     concat(&mut h, &U64_T);
+    hash(&mut h, &U64_T);
     unary_unsigned_default::<u64>(&mut h, &U64_T);
     arith_opr_default::<u64>(&mut h, &U64_T);
+    bit_opr_default::<u64>(&mut h, &U64_T);
     comp_opr_default::<u64>(&mut h, &U64_T);
+    range_opr(&mut h, &U64_T, &U64_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&U64_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(u64 => str, x),
@@ -37,6 +40,8 @@ fn u64_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(u64 => f16, x),
             p if p == *F32_T_VAL => typecast_int!(u64 => f32, x),
             p if p == *F64_T_VAL => typecast_int!(u64 => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(u64 => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(u64 => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn u64_t() -> BuiltinType {
 
 pub static U64_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(u64_t().into()));
 pub static U64_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(u64_t().into()));
+static U64_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&U64_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_unsigned_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_unsigned_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
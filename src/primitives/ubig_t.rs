@@ -12,12 +12,14 @@ fn ubig_t() -> BuiltinType {
     trace!("Initialising ubig");
     h.insert("_default", Value::Ubig(0u8.into()));
     concat(&mut h, &UBIG_T);
+    hash(&mut h, &UBIG_T);
     unary_unsigned_default::<BigUint>(&mut h, &UBIG_T);
-    arith_opr::<BigUint>(&mut h, "_rem", &std::ops::Rem::rem, &IBIG_T);
     arith_opr_big_default::<BigUint>(&mut h, &UBIG_T);
+    bit_opr_big_default::<BigUint>(&mut h, &UBIG_T);
     comp_opr_default::<BigUint>(&mut h, &UBIG_T);
+    range_opr(&mut h, &UBIG_T, &UBIG_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&UBIG_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(BigUint => str, x),
@@ -39,6 +41,8 @@ fn ubig_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(big BigUint => f16, x),
             p if p == *F32_T_VAL => typecast_int!(big BigUint => f32, x),
             p if p == *F64_T_VAL => typecast_int!(big BigUint => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(BigUint => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(BigUint => frac, x),
             _ => return None,
         })
     });
@@ -54,6 +58,7 @@ fn ubig_t() -> BuiltinType {
 
 pub static UBIG_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(ubig_t().into()));
 pub static UBIG_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(ubig_t().into()));
+static UBIG_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&UBIG_T)));
 
 use std::sync::Arc;
 
@@ -62,8 +67,8 @@ use num::BigUint;
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr, arith_opr_big_default, comp_opr_default, concat, get_param, type_cast,
-        unary_unsigned_default,
+        arith_opr_big_default, bit_opr_big_default, comp_opr_default, concat, get_param, hash,
+        range_opr, type_cast, unary_unsigned_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
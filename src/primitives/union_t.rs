@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::Type;
+/// `_U[T1, T2, ...]`, which stands for the union of its type arguments rather than a type of its own
+fn union_t() -> BuiltinType {
+    trace!("Initialising _U");
+    BuiltinType {
+        name: Some(Ident::new("_U")),
+        namespace: HashMap::default(),
+        fields: HashMap::default(),
+        type_args: vec![],
+    }
+}
+
+pub static UNION_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(union_t().into()));
+pub static UNION_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(union_t().into()));
+
+use std::sync::Arc;
+
+use crate::{
+    ast::Ident,
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -22,6 +22,7 @@ fn unit_t() -> BuiltinType {
     trace!("Initialising unit");
     h.insert("_default", Value::Unit);
     concat(&mut h, &UNIT_T);
+    hash(&mut h, &UNIT_T);
     comp_opr_unit(&mut h, "_eq", true);
     comp_opr_unit(&mut h, "_ne", false);
     comp_opr_unit(&mut h, "_gt", false);
@@ -29,7 +30,7 @@ fn unit_t() -> BuiltinType {
     comp_opr_unit(&mut h, "_lt", false);
     comp_opr_unit(&mut h, "_le", true);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&UNIT_T_VAL)),
             p if p == *STR_T_VAL => Value::Str("()".into()),
@@ -53,6 +54,6 @@ use std::sync::Arc;
 
 use crate::{
     ast::Ident,
-    primitives::utils::{binary, concat, get_param, type_cast},
+    primitives::utils::{binary, concat, get_param, hash, type_cast},
     types::r#type::{BuiltinType, ValueType},
 };
@@ -11,11 +11,14 @@ fn usize_t() -> BuiltinType {
     trace!("Initialising usize");
     h.insert("_default", Value::Usize(0));
     concat(&mut h, &USIZE_T);
+    hash(&mut h, &USIZE_T);
     unary_unsigned_default::<usize>(&mut h, &USIZE_T);
     arith_opr_default::<usize>(&mut h, &USIZE_T);
+    bit_opr_default::<usize>(&mut h, &USIZE_T);
     comp_opr_default::<usize>(&mut h, &USIZE_T);
+    range_opr(&mut h, &USIZE_T, &USIZE_RANGE_T);
 
-    let typecast = Arc::new(|x: &Vec<Value>| {
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
         Some(match get_param::<Arc<ValueType>>(x, 1)? {
             p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&USIZE_T_VAL)),
             p if p == *STR_T_VAL => typecast_int!(usize => str, x),
@@ -37,6 +40,8 @@ fn usize_t() -> BuiltinType {
             p if p == *F16_T_VAL => typecast_int!(usize => f16, x),
             p if p == *F32_T_VAL => typecast_int!(usize => f32, x),
             p if p == *F64_T_VAL => typecast_int!(usize => f64, x),
+            p if p == *CPX_T_VAL => typecast_int!(usize => cpx, x),
+            p if p == *FRAC_T_VAL => typecast_int!(usize => frac, x),
             _ => return None,
         })
     });
@@ -52,13 +57,15 @@ fn usize_t() -> BuiltinType {
 
 pub static USIZE_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(usize_t().into()));
 pub static USIZE_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(usize_t().into()));
+static USIZE_RANGE_T: Lazy<Arc<Type>> = Lazy::new(|| generic_range(Arc::clone(&USIZE_T)));
 
 use std::sync::Arc;
 
 use crate::{
     ast::Ident,
     primitives::utils::{
-        arith_opr_default, comp_opr_default, concat, get_param, type_cast, unary_unsigned_default,
+        arith_opr_default, bit_opr_default, comp_opr_default, concat, get_param, hash, range_opr,
+        type_cast, unary_unsigned_default,
     },
     types::r#type::{BuiltinType, ValueType},
 };
@@ -1,19 +1,29 @@
 use std::{
     cmp::PartialOrd,
-    collections::HashMap,
-    ops::{Add, Div, Mul, Rem, Sub},
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    iter::once,
+    mem,
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub},
     sync::Arc,
 };
 
+use num::Complex;
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Float, Signed, Unsigned,
+    checked_pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl,
+    CheckedShr, CheckedSub, Float, One, PrimInt, Signed, ToPrimitive, Unsigned, Zero,
 };
 use once_cell::sync::Lazy;
 
 use crate::{
-    primitives::{LazyGenericProc, ANY_T, BOOL_T, STR_T, TYPE_T},
+    errors::ZError,
+    primitives::{
+        frac_new, is_zero, Fraction, LazyGenericProc, RestParam, ANY_T, BOOL_T, STR_T, TYPE_T,
+        U64_T,
+    },
     types::{
         r#type::Type,
+        sym_table::InterpretSymTable,
         value::{BuiltinFunction, Proc, Value, ValueInner},
     },
 };
@@ -28,6 +38,18 @@ pub fn unary<'a>(
     f: Arc<BuiltinFunction>,
     arg_ty: &'static Lazy<Arc<Type>>,
     ret_ty: &'static Lazy<Arc<Type>>,
+) {
+    unary_with_err(h, n, f, ZError::i001, arg_ty, ret_ty);
+}
+
+/// Like `unary`, but raises `fail_err` instead of `I001` when `f` returns `None`
+pub fn unary_with_err<'a>(
+    h: &mut HashMap<&'a str, Value>,
+    n: &'a str,
+    f: Arc<BuiltinFunction>,
+    fail_err: fn(&[Value]) -> ZError,
+    arg_ty: &'static Lazy<Arc<Type>>,
+    ret_ty: &'static Lazy<Arc<Type>>,
 ) {
     h.insert(
         n,
@@ -35,6 +57,8 @@ pub fn unary<'a>(
             id: Arc::as_ptr(&f) as *const () as usize,
             f,
             ty: LazyGenericProc::new(vec![arg_ty], ret_ty),
+            updates_receiver: false,
+            fail_err,
         }),
     );
 }
@@ -46,21 +70,21 @@ pub fn unary_signed_default<T: Signed + CheckedNeg + ValueInner>(
     unary(
         h,
         "_un_add",
-        Arc::new(|x: &Vec<Value>| Some(x[0].to_owned())),
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
         this_ty,
         this_ty,
     );
     unary(
         h,
         "_un_sub",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<T>(x, 0)?.checked_neg()?.into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<T>(x, 0)?.checked_neg()?.into())),
         this_ty,
         this_ty,
     );
     unary(
         h,
         "_not",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<T>(x, 0)?.is_zero().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<T>(x, 0)?.is_zero().into())),
         this_ty,
         &BOOL_T,
     );
@@ -73,14 +97,14 @@ pub fn unary_unsigned_default<T: Unsigned + ValueInner>(
     unary(
         h,
         "_un_add",
-        Arc::new(|x: &Vec<Value>| Some(x[0].to_owned())),
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
         this_ty,
         this_ty,
     );
     unary(
         h,
         "_not",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<T>(x, 0)?.is_zero().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<T>(x, 0)?.is_zero().into())),
         this_ty,
         &BOOL_T,
     );
@@ -93,21 +117,21 @@ pub fn unary_float_default<T: Float + ValueInner>(
     unary(
         h,
         "_un_add",
-        Arc::new(|x: &Vec<Value>| Some(x[0].to_owned())),
+        Arc::new(|x: &mut Vec<Value>| Some(x[0].to_owned())),
         this_ty,
         this_ty,
     );
     unary(
         h,
         "_un_sub",
-        Arc::new(|x: &Vec<Value>| Some(get_param::<T>(x, 0)?.neg().into())),
+        Arc::new(|x: &mut Vec<Value>| Some(get_param::<T>(x, 0)?.neg().into())),
         this_ty,
         this_ty,
     );
     unary(
         h,
         "_not",
-        Arc::new(|x: &Vec<Value>| {
+        Arc::new(|x: &mut Vec<Value>| {
             Some(
                 (get_param::<T>(x, 0)?.is_zero() || get_param::<T>(x, 0)?.eq(&T::neg_zero()))
                     .into(),
@@ -125,6 +149,19 @@ pub fn binary<'a>(
     arg1_ty: &'static Lazy<Arc<Type>>,
     arg2_ty: &'static Lazy<Arc<Type>>,
     ret_ty: &'static Lazy<Arc<Type>>,
+) {
+    binary_with_err(h, n, f, ZError::i001, arg1_ty, arg2_ty, ret_ty);
+}
+
+/// Like `binary`, but raises `fail_err` instead of `I001` when `f` returns `None`
+pub fn binary_with_err<'a>(
+    h: &mut HashMap<&'a str, Value>,
+    n: &'a str,
+    f: Arc<BuiltinFunction>,
+    fail_err: fn(&[Value]) -> ZError,
+    arg1_ty: &'static Lazy<Arc<Type>>,
+    arg2_ty: &'static Lazy<Arc<Type>>,
+    ret_ty: &'static Lazy<Arc<Type>>,
 ) {
     h.insert(
         n,
@@ -132,6 +169,8 @@ pub fn binary<'a>(
             id: Arc::as_ptr(&f) as *const () as usize,
             f,
             ty: LazyGenericProc::new(vec![arg1_ty, arg2_ty], ret_ty),
+            updates_receiver: false,
+            fail_err,
         }),
     );
 }
@@ -162,6 +201,18 @@ macro_rules! typecast_int {
     (big $v:ty => f16, $x:ident) => {
         Value::F16(f16::from_f64(get_param::<$v>($x, 0)?.to_f64()?))
     };
+    ($v:ty => cpx, $x:ident) => {
+        Value::from(num::Complex::new(
+            Value::from(get_param::<$v>($x, 0)?),
+            Value::from(<$v as num_traits::Zero>::zero()),
+        ))
+    };
+    ($v:ty => frac, $x:ident) => {
+        Value::from($crate::primitives::frac_new(
+            &get_param::<$v>($x, 0)?.into(),
+            &<$v as num_traits::One>::one().into(),
+        )?)
+    };
     ($v:ty => $vo:ident, $x:ident) => {
         Value::$vo(get_param::<$v>($x, 0)?.try_into().ok()?)
     };
@@ -181,6 +232,12 @@ macro_rules! typecast_float {
     (f64 => f16, $x:ident) => {
         Value::F16(f16::from_f64(get_param::<f64>($x, 0)?))
     };
+    ($v:ty => cpx, $x:ident) => {
+        Value::from(num::Complex::new(
+            Value::from(get_param::<$v>($x, 0)?),
+            Value::from(<$v as num_traits::Zero>::zero()),
+        ))
+    };
     ($v:ty => $vo:ident $f:ident, $x:ident) => {
         Value::$vo(get_param::<$v>($x, 0)?.$f()?)
     };
@@ -195,7 +252,7 @@ pub fn arith_opr<'a, T: ValueInner>(
     binary(
         h,
         n,
-        Arc::new(|x: &Vec<Value>| Some(f(get_param::<T>(x, 0)?, get_param::<T>(x, 1)?).into())),
+        Arc::new(|x: &mut Vec<Value>| Some(f(get_param::<T>(x, 0)?, get_param::<T>(x, 1)?).into())),
         this_ty,
         this_ty,
         this_ty,
@@ -211,15 +268,30 @@ pub fn arith_opr_op<'a, T: ValueInner>(
     binary(
         h,
         n,
-        Arc::new(|x: &Vec<Value>| Some(f(&get_param::<T>(x, 0)?, &get_param::<T>(x, 1)?)?.into())),
+        Arc::new(|x: &mut Vec<Value>| {
+            Some(f(&get_param::<T>(x, 0)?, &get_param::<T>(x, 1)?)?.into())
+        }),
         this_ty,
         this_ty,
         this_ty,
     );
 }
 
+/// Raises `a` to the power of `b`, or `None` if `b` is negative or the result overflows
+fn int_pow<T: CheckedMul + One + ToPrimitive + Clone>(a: &T, b: &T) -> Option<T> {
+    checked_pow(a.to_owned(), b.to_usize()?)
+}
+
 pub fn arith_opr_default<
-    T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem + ValueInner,
+    T: CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + CheckedRem
+        + One
+        + ToPrimitive
+        + Clone
+        + ValueInner,
 >(
     h: &mut HashMap<&str, Value>,
     this_ty: &'static Lazy<Arc<Type>>,
@@ -229,10 +301,20 @@ pub fn arith_opr_default<
     arith_opr_op(h, "_mul", &T::checked_mul, this_ty);
     arith_opr_op(h, "_div", &T::checked_div, this_ty);
     arith_opr_op(h, "_rem", &T::checked_rem, this_ty);
+    arith_opr_op(h, "_pow", &int_pow::<T>, this_ty);
 }
 
 pub fn arith_opr_big_default<
-    T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + Rem<T> + ValueInner,
+    T: CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + Rem<Output = T>
+        + Zero
+        + One
+        + ToPrimitive
+        + Clone
+        + ValueInner,
 >(
     h: &mut HashMap<&str, Value>,
     this_ty: &'static Lazy<Arc<Type>>,
@@ -241,6 +323,13 @@ pub fn arith_opr_big_default<
     arith_opr_op(h, "_sub", &T::checked_sub, this_ty);
     arith_opr_op(h, "_mul", &T::checked_mul, this_ty);
     arith_opr_op(h, "_div", &T::checked_div, this_ty);
+    arith_opr_op(
+        h,
+        "_rem",
+        &|a: &T, b: &T| (!b.is_zero()).then(|| a.to_owned() % b.to_owned()),
+        this_ty,
+    );
+    arith_opr_op(h, "_pow", &int_pow::<T>, this_ty);
 }
 
 pub fn arith_opr_float_default<T: Float + ValueInner>(
@@ -252,6 +341,237 @@ pub fn arith_opr_float_default<T: Float + ValueInner>(
     arith_opr(h, "_mul", &Mul::<T>::mul, this_ty);
     arith_opr(h, "_div", &Div::<T>::div, this_ty);
     arith_opr(h, "_rem", &Rem::<T>::rem, this_ty);
+    arith_opr(h, "_pow", &T::powf, this_ty);
+}
+
+/// Adds `..`, which makes a `range` from two values of `this_ty`
+pub fn range_opr(
+    h: &mut HashMap<&str, Value>,
+    this_ty: &'static Lazy<Arc<Type>>,
+    range_ty: &'static Lazy<Arc<Type>>,
+) {
+    binary(
+        h,
+        "_range",
+        Arc::new(|x: &mut Vec<Value>| {
+            Some(Value::Range(Box::new(x[0].to_owned()..x[1].to_owned())))
+        }),
+        this_ty,
+        this_ty,
+        range_ty,
+    );
+}
+
+pub fn bit_compl<T: Not<Output = T> + ValueInner>(
+    h: &mut HashMap<&str, Value>,
+    this_ty: &'static Lazy<Arc<Type>>,
+) {
+    unary(
+        h,
+        "compl",
+        Arc::new(|x: &mut Vec<Value>| Some((!get_param::<T>(x, 0)?).into())),
+        this_ty,
+        this_ty,
+    );
+}
+
+/// Adds a shift by a non-negative amount of the same type, raising `I004` if `f` fails
+pub fn shift_opr<'a, T: ToPrimitive + ValueInner>(
+    h: &mut HashMap<&'a str, Value>,
+    n: &'a str,
+    f: &'static (dyn Fn(T, u32) -> Option<T> + Send + Sync),
+    this_ty: &'static Lazy<Arc<Type>>,
+) {
+    binary_with_err(
+        h,
+        n,
+        Arc::new(|x: &mut Vec<Value>| {
+            let amount = get_param::<T>(x, 1)?.to_u32()?;
+            Some(f(get_param::<T>(x, 0)?, amount)?.into())
+        }),
+        ZError::i004,
+        this_ty,
+        this_ty,
+        this_ty,
+    );
+}
+
+pub fn bit_opr_default<T: PrimInt + CheckedShl + CheckedShr + ValueInner>(
+    h: &mut HashMap<&str, Value>,
+    this_ty: &'static Lazy<Arc<Type>>,
+) {
+    bit_compl::<T>(h, this_ty);
+    arith_opr(h, "and", &BitAnd::<T>::bitand, this_ty);
+    arith_opr(h, "or", &BitOr::<T>::bitor, this_ty);
+    arith_opr(h, "xor", &BitXor::<T>::bitxor, this_ty);
+    shift_opr(h, "lsh", &|a: T, n| a.checked_shl(n), this_ty);
+    shift_opr(h, "rsh", &|a: T, n| a.checked_shr(n), this_ty);
+    shift_opr(
+        h,
+        "zrsh",
+        &|a: T, n| (n < T::zero().count_zeros()).then(|| a.unsigned_shr(n)),
+        this_ty,
+    );
+    // on integers, `x << n` and `x >> n` are the same as `x \< n` and `x \> n`
+    h.insert("_insert", h["lsh"].to_owned());
+    h.insert("_extract", h["rsh"].to_owned());
+}
+
+pub fn bit_opr_big_default<
+    T: BitAnd<Output = T>
+        + BitOr<Output = T>
+        + BitXor<Output = T>
+        + Shl<u32, Output = T>
+        + Shr<u32, Output = T>
+        + Zero
+        + PartialOrd
+        + ToPrimitive
+        + ValueInner,
+>(
+    h: &mut HashMap<&str, Value>,
+    this_ty: &'static Lazy<Arc<Type>>,
+) {
+    arith_opr(h, "and", &BitAnd::<T>::bitand, this_ty);
+    arith_opr(h, "or", &BitOr::<T>::bitor, this_ty);
+    arith_opr(h, "xor", &BitXor::<T>::bitxor, this_ty);
+    shift_opr(h, "lsh", &|a: T, n| Some(a << n), this_ty);
+    shift_opr(h, "rsh", &|a: T, n| Some(a >> n), this_ty);
+    // there are no leading bits to fill with zeroes for a negative big integer
+    shift_opr(
+        h,
+        "zrsh",
+        &|a: T, n| (a >= T::zero()).then(|| a >> n),
+        this_ty,
+    );
+    // on integers, `x << n` and `x >> n` are the same as `x \< n` and `x \> n`
+    h.insert("_insert", h["lsh"].to_owned());
+    h.insert("_extract", h["rsh"].to_owned());
+}
+
+/// Calls the operator `n` on two components of a `cpx` or `frac`
+pub fn component_opr(n: &str, a: &Value, b: &Value) -> Option<Value> {
+    call_method(n, vec![a.to_owned(), b.to_owned()])
+}
+
+fn cpx_add(a: &Complex<Value>, b: &Complex<Value>) -> Option<Complex<Value>> {
+    Some(Complex::new(
+        component_opr("_add", &a.re, &b.re)?,
+        component_opr("_add", &a.im, &b.im)?,
+    ))
+}
+
+fn cpx_sub(a: &Complex<Value>, b: &Complex<Value>) -> Option<Complex<Value>> {
+    Some(Complex::new(
+        component_opr("_sub", &a.re, &b.re)?,
+        component_opr("_sub", &a.im, &b.im)?,
+    ))
+}
+
+fn cpx_mul(a: &Complex<Value>, b: &Complex<Value>) -> Option<Complex<Value>> {
+    let (ac, bd) = (
+        component_opr("_mul", &a.re, &b.re)?,
+        component_opr("_mul", &a.im, &b.im)?,
+    );
+    let (ad, bc) = (
+        component_opr("_mul", &a.re, &b.im)?,
+        component_opr("_mul", &a.im, &b.re)?,
+    );
+    Some(Complex::new(
+        component_opr("_sub", &ac, &bd)?,
+        component_opr("_add", &ad, &bc)?,
+    ))
+}
+
+fn cpx_div(a: &Complex<Value>, b: &Complex<Value>) -> Option<Complex<Value>> {
+    let den = component_opr(
+        "_add",
+        &component_opr("_mul", &b.re, &b.re)?,
+        &component_opr("_mul", &b.im, &b.im)?,
+    )?;
+    let (ac, bd) = (
+        component_opr("_mul", &a.re, &b.re)?,
+        component_opr("_mul", &a.im, &b.im)?,
+    );
+    let (ad, bc) = (
+        component_opr("_mul", &a.re, &b.im)?,
+        component_opr("_mul", &a.im, &b.re)?,
+    );
+    Some(Complex::new(
+        component_opr("_div", &component_opr("_add", &ac, &bd)?, &den)?,
+        component_opr("_div", &component_opr("_sub", &bc, &ad)?, &den)?,
+    ))
+}
+
+pub fn arith_opr_cpx_default(h: &mut HashMap<&str, Value>, this_ty: &'static Lazy<Arc<Type>>) {
+    arith_opr_op(h, "_add", &cpx_add, this_ty);
+    arith_opr_op(h, "_sub", &cpx_sub, this_ty);
+    arith_opr_op(h, "_mul", &cpx_mul, this_ty);
+    arith_opr_op(h, "_div", &cpx_div, this_ty);
+}
+
+/// The numerators of `a` and `b` over their common denominator
+fn cross_num(a: &Fraction, b: &Fraction) -> Option<(Value, Value)> {
+    Some((
+        component_opr("_mul", &a.num, &b.den)?,
+        component_opr("_mul", &b.num, &a.den)?,
+    ))
+}
+
+fn frac_add(a: &Fraction, b: &Fraction) -> Option<Fraction> {
+    let (l, r) = cross_num(a, b)?;
+    frac_new(
+        &component_opr("_add", &l, &r)?,
+        &component_opr("_mul", &a.den, &b.den)?,
+    )
+}
+
+fn frac_sub(a: &Fraction, b: &Fraction) -> Option<Fraction> {
+    let (l, r) = cross_num(a, b)?;
+    frac_new(
+        &component_opr("_sub", &l, &r)?,
+        &component_opr("_mul", &a.den, &b.den)?,
+    )
+}
+
+fn frac_mul(a: &Fraction, b: &Fraction) -> Option<Fraction> {
+    frac_new(
+        &component_opr("_mul", &a.num, &b.num)?,
+        &component_opr("_mul", &a.den, &b.den)?,
+    )
+}
+
+fn frac_div(a: &Fraction, b: &Fraction) -> Option<Fraction> {
+    let (l, r) = cross_num(a, b)?;
+    frac_new(&l, &r)
+}
+
+/// Raises `a` to the power of `b`, or `None` if `b` is not a whole number
+fn frac_pow(a: &Fraction, b: &Fraction) -> Option<Fraction> {
+    if !is_zero(&component_opr("_rem", &b.num, &b.den)?)? {
+        return None;
+    }
+    let n = component_opr("_div", &b.num, &b.den)?;
+    let zero = n.value_ty().namespace().get("_default")?.to_owned();
+    if component_opr("_lt", &n, &zero)?.into_bool().ok()? {
+        let n = call_method("_un_sub", vec![n])?;
+        frac_new(
+            &component_opr("_pow", &a.den, &n)?,
+            &component_opr("_pow", &a.num, &n)?,
+        )
+    } else {
+        frac_new(
+            &component_opr("_pow", &a.num, &n)?,
+            &component_opr("_pow", &a.den, &n)?,
+        )
+    }
+}
+
+pub fn arith_opr_frac_default(h: &mut HashMap<&str, Value>, this_ty: &'static Lazy<Arc<Type>>) {
+    arith_opr_op(h, "_add", &frac_add, this_ty);
+    arith_opr_op(h, "_sub", &frac_sub, this_ty);
+    arith_opr_op(h, "_mul", &frac_mul, this_ty);
+    arith_opr_op(h, "_div", &frac_div, this_ty);
+    arith_opr_op(h, "_pow", &frac_pow, this_ty);
 }
 
 pub fn comp_opr<'a, T: ValueInner>(
@@ -263,7 +583,9 @@ pub fn comp_opr<'a, T: ValueInner>(
     binary(
         h,
         n,
-        Arc::new(|x: &Vec<Value>| Some(f(&get_param::<T>(x, 0)?, &get_param::<T>(x, 1)?).into())),
+        Arc::new(|x: &mut Vec<Value>| {
+            Some(f(&get_param::<T>(x, 0)?, &get_param::<T>(x, 1)?).into())
+        }),
         this_ty,
         this_ty,
         &BOOL_T,
@@ -282,17 +604,120 @@ pub fn comp_opr_default<T: PartialOrd<T> + ValueInner>(
     comp_opr(h, "_le", &T::le, this_ty);
 }
 
+/// Compares two `frac`s by comparing their numerators over a common denominator
+pub fn comp_opr_frac_default(h: &mut HashMap<&str, Value>, this_ty: &'static Lazy<Arc<Type>>) {
+    for n in ["_eq", "_ne", "_gt", "_ge", "_lt", "_le"] {
+        binary(
+            h,
+            n,
+            Arc::new(move |x: &mut Vec<Value>| {
+                let (l, r) =
+                    cross_num(&get_param::<Fraction>(x, 0)?, &get_param::<Fraction>(x, 1)?)?;
+                component_opr(n, &l, &r)
+            }),
+            this_ty,
+            this_ty,
+            &BOOL_T,
+        );
+    }
+}
+
 pub fn concat(h: &mut HashMap<&str, Value>, this_ty: &'static Lazy<Arc<Type>>) {
     binary(
         h,
         "_concat",
-        Arc::new(|x: &Vec<Value>| Some(Value::Str(format!("{}{}", x[0], x[1])))),
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Str(format!("{}{}", x[0], x[1])))),
         this_ty,
         &ANY_T,
         &STR_T,
     );
 }
 
+pub fn hash(h: &mut HashMap<&str, Value>, this_ty: &'static Lazy<Arc<Type>>) {
+    unary(
+        h,
+        "_hash",
+        Arc::new(|x: &mut Vec<Value>| {
+            let mut hasher = DefaultHasher::new();
+            format!("{:?}", x[0]).hash(&mut hasher);
+            Some(Value::U64(hasher.finish()))
+        }),
+        this_ty,
+        &U64_T,
+    );
+}
+
+/// Calls the method `n` from the namespace of the type of `x[0]`, for protocols like `_hash` that
+/// user types can implement
+pub fn call_method(n: &str, mut x: Vec<Value>) -> Option<Value> {
+    match x[0].value_ty().namespace().get(n)?.as_proc()? {
+        Proc::Builtin { f, .. } => f(&mut x),
+        proc @ Proc::Defined { .. } => proc.call(x, &mut InterpretSymTable::default()).ok(),
+    }
+}
+
+pub fn key_hash(key: &Value) -> Option<u64> {
+    call_method("_hash", vec![key.to_owned()])?.into_u64().ok()
+}
+
+pub fn key_eq(a: &Value, b: &Value) -> Option<bool> {
+    call_method("_eq", vec![a.to_owned(), b.to_owned()])?
+        .into_bool()
+        .ok()
+}
+
+/// Adds a method that takes `&$`, where `f` updates the receiver in place and returns the result,
+/// leaving the receiver as it was if it fails
+pub fn in_place<'a>(
+    h: &mut HashMap<&'a str, Value>,
+    n: &'a str,
+    f: Arc<BuiltinFunction>,
+    arg_tys: Vec<&'static Lazy<Arc<Type>>>,
+    ret_ty: &'static Lazy<Arc<Type>>,
+) {
+    h.insert(
+        n,
+        Value::Proc(Proc::Builtin {
+            id: Arc::as_ptr(&f) as *const () as usize,
+            f: Arc::new(move |x: &mut Vec<Value>| {
+                let res = f(x)?;
+                Some(Value::Tup(
+                    vec![mem::replace(&mut x[0], Value::Unit), res].into(),
+                ))
+            }),
+            ty: LazyGenericProc::new(arg_tys, ret_ty),
+            updates_receiver: true,
+            fail_err: ZError::i001,
+        }),
+    );
+}
+
+/// Adds a `_new` that is passed the type, then arguments of `arg_tys`, then any others as `rest`
+/// says
+pub fn ctor(
+    h: &mut HashMap<&str, Value>,
+    f: Arc<BuiltinFunction>,
+    fail_err: fn(&[Value]) -> ZError,
+    arg_tys: Vec<&'static Lazy<Arc<Type>>>,
+    rest: Option<RestParam>,
+    ret_ty: &'static Lazy<Arc<Type>>,
+) {
+    let arg_tys = once(&TYPE_T).chain(arg_tys).collect::<Vec<_>>();
+    h.insert(
+        "_new",
+        Value::Proc(Proc::Builtin {
+            id: Arc::as_ptr(&f) as *const () as usize,
+            f,
+            ty: match rest {
+                Some(rest) => LazyGenericProc::variadic(arg_tys, rest, ret_ty),
+                None => LazyGenericProc::new(arg_tys, ret_ty),
+            },
+            updates_receiver: false,
+            fail_err,
+        }),
+    );
+}
+
 pub fn type_cast(
     h: &mut HashMap<&str, Value>,
     f: Arc<BuiltinFunction>,
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use tracing::trace;
+
+use crate::{primitives::*, types::value::Value, Type};
+#[allow(clippy::cognitive_complexity, clippy::float_cmp)]
+fn vec_t() -> BuiltinType {
+    let mut h = HashMap::new();
+    trace!("Initialising vec");
+    ctor(
+        &mut h,
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Vec(x[1..].to_vec().into()))),
+        ZError::i001,
+        vec![],
+        Some(RestParam::Each(&VEC_ITEM_T)),
+        &VEC_SELF_T,
+    );
+    h.insert("_default", Value::Vec(vec![].into()));
+    binary(
+        &mut h,
+        "_eq",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] == x[1]))),
+        &VEC_SELF_T,
+        &VEC_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_ne",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Bool(x[0] != x[1]))),
+        &VEC_SELF_T,
+        &VEC_SELF_T,
+        &BOOL_T,
+    );
+    binary(
+        &mut h,
+        "_concat",
+        Arc::new(|x: &mut Vec<Value>| {
+            let mut items = x[0].as_vec()?.to_owned();
+            items.extend_from_slice(x[1].as_vec()?);
+            Some(Value::Vec(items))
+        }),
+        &VEC_SELF_T,
+        &VEC_SELF_T,
+        &VEC_SELF_T,
+    );
+    unary(
+        &mut h,
+        "len",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::I32(x[0].as_vec()?.len().try_into().ok()?))),
+        &VEC_SELF_T,
+        &I32_T,
+    );
+    binary_with_err(
+        &mut h,
+        "_index",
+        Arc::new(|x: &mut Vec<Value>| {
+            let i = usize::try_from(get_param::<i32>(x, 1)?).ok()?;
+            x[0].as_vec()?.get(i).cloned()
+        }),
+        ZError::i006,
+        &VEC_SELF_T,
+        &I32_T,
+        &VEC_ITEM_T,
+    );
+    in_place(
+        &mut h,
+        "push",
+        Arc::new(|x: &mut Vec<Value>| {
+            let item = x[1].to_owned();
+            x[0].as_vec_mut()?.push(item);
+            Some(Value::Unit)
+        }),
+        vec![&VEC_SELF_T, &VEC_ITEM_T],
+        &UNIT_T,
+    );
+    in_place(
+        &mut h,
+        "pop",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Opt(x[0].as_vec_mut()?.pop().map(Box::new)))),
+        vec![&VEC_SELF_T],
+        &VEC_ITEM_OPT_T,
+    );
+    in_place(
+        &mut h,
+        "remove",
+        Arc::new(|x: &mut Vec<Value>| {
+            let i = usize::try_from(get_param::<i32>(x, 1)?).ok();
+            let items = x[0].as_vec_mut()?;
+            let item = i
+                .filter(|i| *i < items.len())
+                .map(|i| Box::new(items.remove(i)));
+            Some(Value::Opt(item))
+        }),
+        vec![&VEC_SELF_T, &I32_T],
+        &VEC_ITEM_OPT_T,
+    );
+    // `v << x` pushes x, `v >> i` removes item i
+    h.insert("_insert", h["push"].to_owned());
+    h.insert("_extract", h["remove"].to_owned());
+
+    unary(
+        &mut h,
+        "_iter",
+        Arc::new(|x: &mut Vec<Value>| Some(Value::Iter(x[0].as_vec()?.as_slice().into(), 0))),
+        &VEC_SELF_T,
+        &VEC_ITER_T,
+    );
+
+    let typecast = Arc::new(|x: &mut Vec<Value>| {
+        Some(match get_param::<Arc<ValueType>>(x, 1)? {
+            p if p == *TYPE_T_VAL => Value::Type(Arc::clone(&VEC_T_VAL)),
+            p if p == *STR_T_VAL => Value::Str(x[0].to_string()),
+            p if p == *VEC_T_VAL => x[0].to_owned(),
+            p if p == *ARR_T_VAL => Value::Arr(x[0].as_vec()?.to_owned()),
+            _ => return None,
+        })
+    });
+    type_cast(&mut h, typecast, &VEC_SELF_T);
+
+    BuiltinType {
+        name: Some(Ident::new("vec")),
+        namespace: h.drain().map(|(k, v)| (k.into(), v)).collect(),
+        fields: HashMap::default(),
+        type_args: vec![("T".into(), Arc::clone(&TYPE_T))],
+    }
+}
+
+pub static VEC_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(vec_t().into()));
+pub static VEC_T_VAL: Lazy<Arc<ValueType>> = Lazy::new(|| Arc::new(vec_t().into()));
+static VEC_ITEM_T: Lazy<Arc<Type>> = Lazy::new(|| Arc::new(Type::Param("T".into())));
+static VEC_ITER_T: Lazy<Arc<Type>> = Lazy::new(|| generic_iter(Arc::clone(&VEC_ITEM_T)));
+static VEC_ITEM_OPT_T: Lazy<Arc<Type>> = Lazy::new(|| generic_opt(Arc::clone(&VEC_ITEM_T)));
+static VEC_SELF_T: Lazy<Arc<Type>> = Lazy::new(|| generic_vec(Arc::clone(&VEC_ITEM_T)));
+
+#[must_use]
+pub fn generic_vec(item: Arc<Type>) -> Arc<Type> {
+    Type::generic(
+        &VEC_T,
+        vec![("T".into(), Either::Right(Either::Right(item)))],
+    )
+}
+
+use std::sync::Arc;
+
+use itertools::Either;
+
+use crate::{
+    ast::Ident,
+    errors::ZError,
+    primitives::utils::{binary, binary_with_err, ctor, get_param, in_place, type_cast, unary},
+    types::r#type::{BuiltinType, ValueType},
+};
@@ -66,11 +66,15 @@ pub fn repl() -> Result<()> {
                     };
                     continue;
                 }
-                let instructions = match compile(
+                let instructions = compile(
                     &Either::Right((filename.to_owned(), input)),
                     &mut ty_symt,
                     false,
-                ) {
+                );
+                for warning in ty_symt.take_warnings() {
+                    warning.print()?;
+                }
+                let instructions = match instructions {
                     Ok(v) => v,
                     Err(e) => {
                         e.print()?;
@@ -0,0 +1,202 @@
+//! Pattern-coverage analysis for `match` expressions, following the usefulness algorithm from
+//! Maranget's "Warnings for pattern matching"
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use itertools::Itertools;
+use smol_str::SmolStr;
+
+use crate::types::{r#type::Type, value::Value};
+
+/// A constructor that a value of the subject type can be built from
+#[derive(Clone, Debug)]
+pub enum Ctor {
+    Bool(bool),
+    /// A literal of a type with too many values to enumerate, eg `i32` or `str`
+    Literal(Box<Value>),
+    /// The only constructor of a struct, with its field names in a fixed order
+    Struct {
+        ty: Arc<Type>,
+        fields: Vec<SmolStr>,
+    },
+    /// One of the member types of a union
+    Member {
+        ty: Arc<Type>,
+        union: Arc<Type>,
+    },
+}
+impl PartialEq for Ctor {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Literal(a), Self::Literal(b)) => a == b,
+            (Self::Struct { ty: a, .. }, Self::Struct { ty: b, .. })
+            | (Self::Member { ty: a, .. }, Self::Member { ty: b, .. }) => a.same_as(b),
+            _ => false,
+        }
+    }
+}
+impl Ctor {
+    const fn arity(&self) -> usize {
+        match self {
+            Self::Bool(_) | Self::Literal(_) | Self::Member { .. } => 0,
+            Self::Struct { fields, .. } => fields.len(),
+        }
+    }
+    /// Returns the constructors that `ctors` are missing from their type, or `None` if they
+    /// cover every constructor. An empty list means the missing constructors cannot be listed.
+    fn missing(ctors: &[&Self]) -> Option<Vec<Self>> {
+        match ctors.first() {
+            Some(Self::Bool(_)) => {
+                let missing = [true, false]
+                    .into_iter()
+                    .map(Self::Bool)
+                    .filter(|b| !ctors.contains(&b))
+                    .collect_vec();
+                (!missing.is_empty()).then_some(missing)
+            }
+            Some(Self::Member { union, .. }) => {
+                let Some(members) = union.members() else {
+                    unreachable!()
+                };
+                let missing = members
+                    .iter()
+                    .map(|ty| Self::Member {
+                        ty: Arc::clone(ty),
+                        union: Arc::clone(union),
+                    })
+                    .filter(|ctor| !ctors.contains(&ctor))
+                    .collect_vec();
+                (!missing.is_empty()).then_some(missing)
+            }
+            Some(Self::Struct { .. }) => None,
+            Some(Self::Literal(_)) | None => Some(vec![]),
+        }
+    }
+}
+
+/// The set of values a pattern matches
+#[derive(Clone, Debug)]
+pub enum Space {
+    /// Matches every value
+    Any,
+    Ctor(Ctor, Vec<Self>),
+    /// Matches some values that cannot be determined at compile time
+    Opaque,
+    /// Matches no values
+    Empty,
+}
+impl Display for Space {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any | Self::Opaque | Self::Empty => write!(f, "_"),
+            Self::Ctor(Ctor::Bool(b), _) => write!(f, "{b}"),
+            Self::Ctor(Ctor::Literal(v), _) => write!(f, "{v}"),
+            // a variant is matched by its name, eg `E::A`
+            Self::Ctor(Ctor::Member { ty, union }, _) if union.is_enum() => write!(f, "{ty}"),
+            Self::Ctor(Ctor::Member { ty, .. }, _) => write!(f, "istype {ty}"),
+            Self::Ctor(Ctor::Struct { ty, fields }, args) => write!(
+                f,
+                "{ty}({})",
+                fields
+                    .iter()
+                    .zip(args)
+                    .map(|(name, arg)| format!("{name}: {arg}"))
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+fn specialize(rows: &[Vec<Space>], ctor: &Ctor) -> Vec<Vec<Space>> {
+    rows.iter()
+        .filter_map(|row| {
+            let (head, tail) = row.split_first()?;
+            let mut new_row = match head {
+                Space::Ctor(c, args) if c == ctor => args.to_owned(),
+                Space::Any => vec![Space::Any; ctor.arity()],
+                _ => return None,
+            };
+            new_row.extend_from_slice(tail);
+            Some(new_row)
+        })
+        .collect()
+}
+
+fn default(rows: &[Vec<Space>]) -> Vec<Vec<Space>> {
+    rows.iter()
+        .filter_map(|row| match row.split_first()? {
+            (Space::Any, tail) => Some(tail.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn rebuild(ctor: &Ctor, mut witness: Vec<Space>) -> Vec<Space> {
+    let rest = witness.split_off(ctor.arity());
+    let mut new_witness = vec![Space::Ctor(ctor.to_owned(), witness)];
+    new_witness.extend(rest);
+    new_witness
+}
+
+/// Returns a list of values matched by `row` but not by any of `rows`, if there are any
+fn usefulness(rows: &[Vec<Space>], row: &[Space]) -> Option<Vec<Space>> {
+    let Some((head, tail)) = row.split_first() else {
+        return rows.is_empty().then(Vec::new);
+    };
+    match head {
+        Space::Empty => None,
+        Space::Ctor(ctor, args) => {
+            let mut new_row = args.to_owned();
+            new_row.extend_from_slice(tail);
+            usefulness(&specialize(rows, ctor), &new_row).map(|w| rebuild(ctor, w))
+        }
+        Space::Opaque => usefulness(&default(rows), tail).map(|w| {
+            let mut new_witness = vec![Space::Opaque];
+            new_witness.extend(w);
+            new_witness
+        }),
+        Space::Any => {
+            let ctors = rows
+                .iter()
+                .filter_map(|row| match row.first() {
+                    Some(Space::Ctor(ctor, _)) => Some(ctor),
+                    _ => None,
+                })
+                .dedup()
+                .collect_vec();
+            if let Some(missing) = Ctor::missing(&ctors) {
+                usefulness(&default(rows), tail).map(|w| {
+                    let mut new_witness = vec![missing.first().map_or(Space::Any, |ctor| {
+                        Space::Ctor(ctor.to_owned(), vec![Space::Any; ctor.arity()])
+                    })];
+                    new_witness.extend(w);
+                    new_witness
+                })
+            } else {
+                ctors.into_iter().find_map(|ctor| {
+                    let mut new_row = vec![Space::Any; ctor.arity()];
+                    new_row.extend_from_slice(tail);
+                    usefulness(&specialize(rows, ctor), &new_row).map(|w| rebuild(ctor, w))
+                })
+            }
+        }
+    }
+}
+
+/// Whether `space` matches any values not already matched by `spaces`
+#[must_use]
+pub fn is_reachable(spaces: &[Space], space: &Space) -> bool {
+    let rows = spaces.iter().map(|s| vec![s.to_owned()]).collect_vec();
+    usefulness(&rows, std::slice::from_ref(space)).is_some()
+}
+
+/// Returns a value not matched by any of `spaces`, if there is one
+#[must_use]
+pub fn uncovered(spaces: &[Space]) -> Option<Space> {
+    let rows = spaces.iter().map(|s| vec![s.to_owned()]).collect_vec();
+    usefulness(&rows, &[Space::Any]).and_then(|w| w.into_iter().next())
+}
@@ -1,3 +1,4 @@
+pub mod coverage;
 pub mod position;
 pub mod sym_table;
 pub mod token;
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, VecDeque},
     fmt::{Debug, Display, Formatter},
+    mem,
     sync::Arc,
 };
 
@@ -8,7 +9,7 @@ use owo_colors::OwoColorize;
 use smol_str::SmolStr;
 
 use crate::{
-    ast::{Ast, AstData},
+    ast::{Ast, AstData, GenericProc},
     errors::{ToZResult, ZError, ZResult},
     primitives::{I32_T, PRIMS, PRIMS_VAL, TYPE_T},
     types::{
@@ -24,21 +25,28 @@ pub enum TypeCheckFrameType {
     Normal,
     Constants,
     Function(Option<Arc<Type>>),
+    Loop,
 }
 
+/// The frames, the warnings raised so far, and the generic procs declared so far
 #[derive(Debug, Clone)]
-pub struct TypeCheckSymTable(pub VecDeque<TypeCheckFrame>);
+pub struct TypeCheckSymTable(
+    pub VecDeque<TypeCheckFrame>,
+    pub Vec<ZError>,
+    pub Vec<GenericProc>,
+);
 
 #[derive(Debug, Clone)]
 pub struct TypeCheckFrame {
     pub ty: TypeCheckFrameType,
+    pub label: Option<SmolStr>,
     pub table: HashMap<SmolStr, TypeCheckType>,
     pub defer: Vec<Ast>,
 }
 
 impl Default for TypeCheckSymTable {
     fn default() -> Self {
-        let mut table = Self(VecDeque::new());
+        let mut table = Self(VecDeque::new(), vec![], vec![]);
         table.add_frame(TypeCheckFrameType::Constants);
         for (k, v) in &*PRIMS {
             table
@@ -63,18 +71,27 @@ impl TypeCheckSymTable {
             table: HashMap::new(),
             defer: vec![],
             ty,
+            label: None,
         });
         self.front_mut().unwrap_or_else(|_| unreachable!())
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn set_block_return(&mut self, ty: Arc<Type>, span: impl GetSpan) -> ZResult<()> {
+    pub fn set_block_return(
+        &mut self,
+        ty: Arc<Type>,
+        label: Option<&str>,
+        span: impl GetSpan,
+    ) -> ZResult<()> {
         for frame in &mut self.0 {
+            if label.is_some() && frame.label.as_deref() != label {
+                continue;
+            }
             if let TypeCheckFrameType::Function(ret_ty)
             | TypeCheckFrameType::NormalReturnable(ret_ty) = &mut frame.ty
             {
                 if let Some(ret_ty) = ret_ty {
-                    if !Arc::ptr_eq(ret_ty, &ty) {
+                    if !ty.is_assignable_to(ret_ty) {
                         return Err(ZError::t003(ret_ty, &ty).with_span(span));
                     }
                 } else {
@@ -83,7 +100,27 @@ impl TypeCheckSymTable {
                 return Ok(());
             }
         }
-        Err(ZError::t017().with_span(span))
+        Err(label
+            .map_or_else(ZError::t017, ZError::t020)
+            .with_span(span))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn check_loop(&self, kwd: &str, label: Option<&str>, span: impl GetSpan) -> ZResult<()> {
+        for frame in &self.0 {
+            match &frame.ty {
+                TypeCheckFrameType::Loop => {
+                    if label.is_none() || frame.label.as_deref() == label {
+                        return Ok(());
+                    }
+                }
+                TypeCheckFrameType::Function(_) | TypeCheckFrameType::NormalReturnable(_) => break,
+                _ => (),
+            }
+        }
+        Err(label
+            .map_or_else(|| ZError::t018(kwd), ZError::t019)
+            .with_span(span))
     }
 
     #[tracing::instrument(skip(self))]
@@ -105,6 +142,12 @@ impl TypeCheckSymTable {
         self.front_mut()?.table.insert(name.into(), value);
         Ok(())
     }
+    pub fn warn(&mut self, warning: ZError) {
+        self.1.push(warning);
+    }
+    pub fn take_warnings(&mut self) -> Vec<ZError> {
+        std::mem::take(&mut self.1)
+    }
     pub fn pop_frame(&mut self) -> ZResult<()> {
         let mut temp_self = self.to_owned();
         for defer in &mut self.front_mut()?.defer {
@@ -116,8 +159,8 @@ impl TypeCheckSymTable {
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn set_val(&mut self, name: &str, value: TypeCheckType, span: impl GetSpan) -> ZResult<()> {
-        if Arc::ptr_eq(&value, &TYPE_T) {
+    pub fn set_val(&mut self, name: &str, value: &TypeCheckType, span: impl GetSpan) -> ZResult<()> {
+        if Arc::ptr_eq(value, &TYPE_T) {
             return Err(ZError::t001().with_span(span));
         }
         let mut only_consts = false;
@@ -128,10 +171,9 @@ impl TypeCheckSymTable {
                 if frame.ty == TypeCheckFrameType::Constants {
                     return Err(ZError::t001().with_span(span));
                 }
-                if !Arc::ptr_eq(&frame.table[name], &value) {
-                    return Err(ZError::t011(&frame.table[name], &value).with_span(span));
+                if !value.is_assignable_to(&frame.table[name]) {
+                    return Err(ZError::t011(&frame.table[name], value).with_span(span));
                 }
-                frame.table.insert(name.into(), value);
                 return Ok(());
             }
             if let TypeCheckFrameType::Function(_) = frame.ty {
@@ -192,6 +234,7 @@ pub struct InterpretSymTable(pub VecDeque<InterpretFrame>);
 #[derive(Debug, Clone)]
 pub struct InterpretFrame {
     pub ty: InterpretFrameType,
+    pub label: Option<SmolStr>,
     pub table: HashMap<SmolStr, Value>,
     pub defer: Vec<Ast>,
 }
@@ -219,6 +262,7 @@ impl InterpretSymTable {
             table: HashMap::new(),
             defer: vec![],
             ty,
+            label: None,
         });
         &mut self.0[0]
     }
@@ -280,6 +324,29 @@ impl InterpretSymTable {
         }
         Err(ZError::t002(name).with_span(span))
     }
+    /// Moves the value of a variable out, leaving `unit` until it is set again, so that it can be
+    /// updated without being copied. Constants can't be taken
+    #[tracing::instrument(skip(self))]
+    pub fn take_val(&mut self, name: &str) -> Option<Value> {
+        let mut only_consts = false;
+        for frame in &mut self.0 {
+            if (only_consts && frame.ty == InterpretFrameType::Constants)
+                || frame.table.contains_key(name)
+            {
+                if frame.ty == InterpretFrameType::Constants {
+                    return None;
+                }
+                return frame
+                    .table
+                    .get_mut(name)
+                    .map(|v| mem::replace(v, Value::Unit));
+            }
+            if frame.ty == InterpretFrameType::Function {
+                only_consts = true;
+            }
+        }
+        None
+    }
     #[tracing::instrument(skip(self))]
     pub fn delete_val(&mut self, name: &str, span: impl GetSpan) -> ZResult<Value> {
         let Some(first_frame) = self.0.front_mut() else {
@@ -38,6 +38,7 @@ pub enum OprType {
     UnPlus,
     UnMinus,
     Not,
+    BitCompl,
     Pow,
     Mul,
     Div,
@@ -46,6 +47,14 @@ pub enum OprType {
     Sub,
     AddSub,
     SubAdd,
+    Lsh,
+    Rsh,
+    Zrsh,
+    Insert,
+    Extract,
+    BitAnd,
+    BitXor,
+    BitOr,
     And,
     Or,
     Gt,
@@ -61,9 +70,17 @@ pub enum OprType {
     Iseq,
     Isnteq,
     Concat,
+    Range,
     Ref,
     Deref,
     TypeCast,
+    Unwrap,
+    Propagate,
+    Inc,
+    Dec,
+    Coalesce,
+    Handle,
+    Pipe,
 }
 impl Display for OprType {
     fn fmt(&self, f: &mut Formatter) -> Result {
@@ -74,11 +91,25 @@ impl OprType {
     #[must_use]
     pub const fn order(&self) -> usize {
         match self {
-            Self::UnPlus | Self::UnMinus | Self::Not | Self::Ref | Self::Deref => 1,
+            Self::UnPlus
+            | Self::UnMinus
+            | Self::Not
+            | Self::BitCompl
+            | Self::Ref
+            | Self::Deref
+            | Self::Unwrap
+            | Self::Propagate
+            | Self::Inc
+            | Self::Dec => 1,
             Self::TypeCast => 2,
             Self::Pow => 3,
             Self::Mul | Self::Div | Self::Mod => 6,
             Self::Add | Self::Sub | Self::AddSub | Self::SubAdd => 8,
+            Self::Lsh | Self::Rsh | Self::Zrsh | Self::Insert | Self::Extract => 9,
+            Self::BitAnd => 10,
+            Self::BitXor => 11,
+            Self::BitOr => 12,
+            Self::Range => 13,
             Self::Gt
             | Self::Lt
             | Self::Ge
@@ -90,10 +121,12 @@ impl OprType {
             | Self::Is
             | Self::Isnt
             | Self::Iseq
-            | Self::Isnteq => 10,
-            Self::And => 14,
+            | Self::Isnteq => 14,
+            Self::And => 15,
             Self::Or => 16,
+            Self::Coalesce | Self::Handle => 17,
             Self::Concat => 18,
+            Self::Pipe => 19,
         }
     }
 }
@@ -116,6 +149,11 @@ pub enum Keyword {
     Do,
     While,
     For,
+    In,
+    Break,
+    Continue,
+    Match,
+    Of,
     Delete,
     Return,
     Proc,
@@ -124,10 +162,12 @@ pub enum Keyword {
     Defer,
     Class,
     Struct,
+    Enum,
 }
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum AccessType {
     Field,
+    OptionalField,
     Method,
     Namespace,
 }
@@ -140,13 +180,16 @@ pub enum TokenType {
     MultilineCommentEnd,            // */
     Flag(Flag),                     // hoi, pub, priv, prot, const
     UnaryOpr(OprType),              // \~, ++, ! etc
+    PostfixOpr(OprType),            // !!, ?, ++, --
     AssignmentOpr(Option<OprType>), // =, += etc
     BinaryOpr(OprType), // +, -, /f, rt, \&, ==, >, is, &&, ||, ^^, .., ><, istype, isnttype etc
-    DotOpr(AccessType), // .
+    DotOpr(AccessType), // ., ?.
     DeclarationOpr,     // :=
     LiteralMisc,        // true, null, etc
     LiteralNumber,      // 3, 24, -34.5 etc
     LiteralString,      // "abc" etc
+    LiteralChar,        // c"a", c"8ac3"
+    LiteralFormatString, // f"a{b}c"
     StatementEnd,       // ;
     OpenParen,          // (
     CloseParen,         // )
@@ -196,6 +239,7 @@ impl TokenType {
                 vec![TokenCategory::Operator, TokenCategory::ValueStart]
             }
             Self::BinaryOpr(..) | Self::DeclarationOpr => vec![TokenCategory::Operator],
+            Self::PostfixOpr(..) => vec![TokenCategory::Operator, TokenCategory::ValueEnd],
             Self::Bar => vec![
                 TokenCategory::Literal,
                 TokenCategory::ValueStart,
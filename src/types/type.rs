@@ -3,7 +3,8 @@ use std::{
     collections::HashMap,
     fmt::{Debug, Display, Formatter},
     ops::Deref,
-    sync::Arc,
+    ptr,
+    sync::{Arc, Weak},
 };
 
 use itertools::{Either, Itertools};
@@ -13,8 +14,8 @@ use smol_str::SmolStr;
 use crate::{
     ast::Ident,
     errors::{ToZResult, ZError, ZResult},
-    primitives::{ANY_T_VAL, PRIMS, PRIMS_VAL, TYPE_T},
-    types::value::Value,
+    primitives::{ANY_T, ANY_T_VAL, PRIMS, PRIMS_VAL, PROC_T, TYPE_T},
+    types::{position::GetSpan, value::Value},
 };
 
 #[derive(Clone, PartialEq)]
@@ -25,18 +26,62 @@ pub enum Type {
         namespace: HashMap<SmolStr, LazyType<Value>>,
         fields: HashMap<SmolStr, Arc<Type>>,
         type_args: Vec<(SmolStr, LazyType<Value>)>,
+        /// The classes that the class inherits from
+        parents: Vec<Arc<Self>>,
+        /// The enum that the type is a variant of, if any
+        variant_of: EnumLink,
     },
     Generic {
-        type_args: Vec<(SmolStr, Either<Value, Either<Vec<Arc<Type>>, Arc<Type>>>)>,
+        type_args: Vec<(SmolStr, TypeArg)>,
         base: Arc<Type>,
     },
+    /// A type parameter of a generic type, substituted when the type is instantiated
+    Param(SmolStr),
+    /// `T1 / T2`, a value of any of the member types
+    Union(Vec<Arc<Self>>),
+    /// An enum, a value of any of its variants.
+    /// Unlike a union, it is distinct from every other type, even one with the same variants.
+    Enum(Vec<Arc<Self>>),
+}
+
+/// The enum that a variant belongs to, which the variant does not keep alive
+#[derive(Clone, Debug, Default)]
+pub struct EnumLink(Weak<Type>);
+impl PartialEq for EnumLink {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ptr_eq(&other.0)
+    }
+}
+
+pub type TypeArg = Either<Value, Either<Vec<Arc<Type>>, Arc<Type>>>;
+
+/// Compares two lists of type arguments, using `f` to compare the types in them
+fn zip_type_args(
+    a: &[(SmolStr, TypeArg)],
+    b: &[(SmolStr, TypeArg)],
+    f: impl Fn(&Arc<Type>, &Arc<Type>) -> bool,
+) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|((k1, v1), (k2, v2))| {
+            k1 == k2
+                && match (v1, v2) {
+                    (Either::Left(v1), Either::Left(v2)) => v1 == v2,
+                    (Either::Right(Either::Left(tys1)), Either::Right(Either::Left(tys2))) => {
+                        tys1.len() == tys2.len() && tys1.iter().zip(tys2).all(|(a, b)| f(a, b))
+                    }
+                    (Either::Right(Either::Right(ty1)), Either::Right(Either::Right(ty2))) => {
+                        f(ty1, ty2)
+                    }
+                    _ => false,
+                }
+        })
 }
 
 #[derive(Clone)]
 pub struct LazyType<T: Clone + Debug> {
     pub data: Option<T>,
     ty: OnceCell<Arc<Type>>,
-    f: Arc<dyn Fn(&Option<T>) -> Arc<Type> + Send + Sync>,
+    f: Arc<dyn Fn(&Option<T>) -> Option<Arc<Type>> + Send + Sync>,
 }
 impl<T: Clone + Debug> PartialEq for LazyType<T> {
     fn eq(&self, other: &Self) -> bool {
@@ -56,14 +101,26 @@ impl<T: Clone + Debug> Display for LazyType<T> {
 impl<T: Clone + Debug> Deref for LazyType<T> {
     type Target = Arc<Type>;
     fn deref(&self) -> &Self::Target {
-        self.ty.get_or_init(|| (self.f)(&self.data))
+        // a type that is not known yet is `_any` until it is
+        self.ty
+            .get_or_try_init(|| (self.f)(&self.data).ok_or(()))
+            .unwrap_or(&ANY_T)
     }
 }
 impl<T: Clone + Debug + 'static> LazyType<T> {
     pub fn new_lazy(data: T, f: fn(&T) -> Arc<Type>) -> Self {
         Self {
             data: Some(data),
-            f: Arc::new(move |v| f(v.as_ref().unwrap())),
+            f: Arc::new(move |v| Some(f(v.as_ref().unwrap()))),
+            ty: OnceCell::new(),
+        }
+    }
+    /// A type computed by `f` when it is first needed and known,
+    /// eg a method that refers to its own class
+    pub fn from_fn(f: impl Fn() -> Option<Arc<Type>> + Send + Sync + 'static) -> Self {
+        Self {
+            data: None,
+            f: Arc::new(move |_| f()),
             ty: OnceCell::new(),
         }
     }
@@ -73,13 +130,13 @@ impl<T: Clone + Debug> From<Arc<Type>> for LazyType<T> {
     fn from(ty: Arc<Type>) -> Self {
         Self {
             data: None,
-            f: Arc::new(move |_| Arc::clone(&ty)),
+            f: Arc::new(move |_| Some(Arc::clone(&ty))),
             ty: OnceCell::new(),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum ValueType {
     Any,
     Type {
@@ -87,27 +144,463 @@ pub enum ValueType {
         namespace: HashMap<SmolStr, Value>,
         fields: HashMap<SmolStr, Arc<Type>>,
         type_args: Vec<(SmolStr, Value)>,
+        /// The classes that the class inherits from
+        parents: Vec<Arc<Self>>,
     },
+    Union(Vec<Arc<Self>>),
+}
+impl PartialEq for ValueType {
+    fn eq(&self, other: &Self) -> bool {
+        // a type is mostly compared with itself, which saves comparing all of its namespace
+        if ptr::eq(self, other) {
+            return true;
+        }
+        match (self, other) {
+            (Self::Any, Self::Any) => true,
+            (
+                Self::Type {
+                    name: n1,
+                    namespace: ns1,
+                    fields: f1,
+                    type_args: t1,
+                    parents: p1,
+                },
+                Self::Type {
+                    name: n2,
+                    namespace: ns2,
+                    fields: f2,
+                    type_args: t2,
+                    parents: p2,
+                },
+            ) => n1 == n2 && ns1 == ns2 && f1 == f2 && t1 == t2 && p1 == p2,
+            (Self::Union(m1), Self::Union(m2)) => m1 == m2,
+            _ => false,
+        }
+    }
 }
 
 impl Type {
     #[must_use]
     pub fn namespace(&self) -> Cow<HashMap<SmolStr, LazyType<Value>>> {
         match self {
-            Self::Any => Cow::Owned(HashMap::new()),
-            Self::Generic { base, .. } => base.namespace(), // TODO type arg substitutions
+            // an enum has the members that all of its variants have, like `_eq`
+            Self::Enum(variants) => Cow::Owned(
+                variants
+                    .first()
+                    .map(|first| first.namespace().into_owned())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|(k, v)| {
+                        variants.iter().all(|variant| {
+                            variant.namespace().get(k).is_some_and(|v2| v2.same_as(v))
+                        })
+                    })
+                    .collect(),
+            ),
+            Self::Any | Self::Param(_) | Self::Union(_) => Cow::Owned(HashMap::new()),
+            Self::Generic { base, type_args } => Cow::Owned(
+                base.namespace()
+                    .iter()
+                    .map(|(k, v)| (k.to_owned(), v.substitute(type_args).into()))
+                    .collect(),
+            ),
             Self::Type { namespace, .. } => Cow::Borrowed(namespace),
         }
     }
     #[must_use]
     pub fn fields(&self) -> Cow<HashMap<SmolStr, Arc<Self>>> {
         match self {
-            Self::Any => Cow::Owned(HashMap::new()),
-            Self::Generic { base, .. } => base.fields(), // TODO type arg substitutions
+            Self::Any | Self::Param(_) | Self::Union(_) | Self::Enum(_) => {
+                Cow::Owned(HashMap::new())
+            }
+            Self::Generic { base, type_args } => Cow::Owned(
+                base.fields()
+                    .iter()
+                    .map(|(k, v)| (k.to_owned(), v.substitute(type_args)))
+                    .collect(),
+            ),
             Self::Type { fields, .. } => Cow::Borrowed(fields),
         }
     }
 
+    /// Whether values of the type can be `hmap` keys or `hset` items, which needs a `_hash` method
+    #[must_use]
+    pub fn is_hashable(&self) -> bool {
+        match self {
+            Self::Any | Self::Param(_) => true,
+            Self::Union(tys) | Self::Enum(tys) => tys.iter().all(|ty| ty.is_hashable()),
+            Self::Type { .. } | Self::Generic { .. } => self.namespace().contains_key("_hash"),
+        }
+    }
+
+    /// Whether the type is one of the builtin integer types
+    #[must_use]
+    pub fn is_int(&self) -> bool {
+        [
+            "i8", "i16", "i32", "i64", "i128", "isize", "ibig", "u8", "u16", "u32", "u64", "u128",
+            "usize", "ubig",
+        ]
+        .into_iter()
+        .any(|name| PRIMS[name].same_as(self))
+    }
+
+    /// Whether the type is one of the builtin integer or float types
+    #[must_use]
+    pub fn is_num(&self) -> bool {
+        self.is_int()
+            || ["f16", "f32", "f64"]
+                .into_iter()
+                .any(|name| PRIMS[name].same_as(self))
+    }
+
+    /// Returns the instance of the generic type `base` with the given type arguments.
+    /// Instances are compared by their contents, so two instances with the same arguments are the
+    /// same type.
+    #[must_use]
+    pub fn generic(base: &Arc<Self>, type_args: Vec<(SmolStr, TypeArg)>) -> Arc<Self> {
+        Arc::new(Self::Generic {
+            type_args,
+            base: Arc::clone(base),
+        })
+    }
+
+    /// Returns the union of `tys`, flattening nested unions.
+    /// Unions are compared by their contents, and the order of their members does not matter.
+    #[must_use]
+    pub fn union(tys: impl IntoIterator<Item = Arc<Self>>) -> Arc<Self> {
+        let mut members: Vec<Arc<Self>> = vec![];
+        for ty in tys {
+            let flattened = match &*ty {
+                Self::Any => return Arc::clone(&ANY_T),
+                Self::Union(tys) => tys.to_owned(),
+                _ => vec![ty],
+            };
+            for ty in flattened {
+                if !members.iter().any(|member| member.same_as(&ty)) {
+                    members.push(ty);
+                }
+            }
+        }
+        if members.len() == 1 {
+            return members.remove(0);
+        }
+        Arc::new(Self::Union(members))
+    }
+
+    /// Returns a new enum with the given variants, linking each variant to it
+    #[must_use]
+    pub fn new_enum(variants: Vec<Self>) -> Arc<Self> {
+        Arc::new_cyclic(|enum_ty| {
+            Self::Enum(
+                variants
+                    .into_iter()
+                    .map(|mut variant| {
+                        if let Self::Type { variant_of, .. } = &mut variant {
+                            *variant_of = EnumLink(Weak::clone(enum_ty));
+                        }
+                        Arc::new(variant)
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    #[must_use]
+    pub const fn is_enum(&self) -> bool {
+        matches!(self, Self::Enum(_))
+    }
+
+    /// Returns the enum that the type is a variant of, if any
+    #[must_use]
+    pub fn parent_enum(&self) -> Option<Arc<Self>> {
+        let Self::Type { variant_of, .. } = self else {
+            return None;
+        };
+        variant_of.0.upgrade()
+    }
+
+    /// Returns the members of a union or the variants of an enum
+    #[must_use]
+    pub fn members(&self) -> Option<&[Arc<Self>]> {
+        match self {
+            Self::Union(tys) | Self::Enum(tys) => Some(tys),
+            _ => None,
+        }
+    }
+
+    /// Returns the classes that the class directly inherits from
+    #[must_use]
+    pub fn parents(&self) -> &[Arc<Self>] {
+        match self {
+            Self::Type { parents, .. } => parents,
+            _ => &[],
+        }
+    }
+
+    /// Whether the two types are the same type.
+    /// Generic instances and unions are compared by their contents, other types by identity.
+    #[must_use]
+    pub fn same_as(&self, other: &Self) -> bool {
+        if std::ptr::eq(self, other) {
+            return true;
+        }
+        match (self, other) {
+            (Self::Any, Self::Any) => true,
+            (Self::Param(a), Self::Param(b)) => a == b,
+            (
+                Self::Generic { base, type_args },
+                Self::Generic {
+                    base: other_base,
+                    type_args: other_args,
+                },
+            ) => base.same_as(other_base) && zip_type_args(type_args, other_args, |a, b| a.same_as(b)),
+            (Self::Union(tys), Self::Union(other_tys)) => {
+                tys.len() == other_tys.len()
+                    && tys.iter().all(|ty| other_tys.iter().any(|other| ty.same_as(other)))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a value of this type can be used where a value of type `other` is expected.
+    /// `_any` can be used as, and in place of, any type.
+    #[must_use]
+    pub fn is_assignable_to(self: &Arc<Self>, other: &Arc<Self>) -> bool {
+        if matches!(**self, Self::Any) || matches!(**other, Self::Any) || self.same_as(other) {
+            return true;
+        }
+        match (&**self, &**other) {
+            (Self::Union(tys) | Self::Enum(tys), _) => {
+                tys.iter().all(|ty| ty.is_assignable_to(other))
+            }
+            (_, Self::Union(tys) | Self::Enum(tys)) => {
+                tys.iter().any(|ty| self.is_assignable_to(ty))
+            }
+            (
+                Self::Generic { base, type_args },
+                Self::Generic {
+                    base: other_base,
+                    type_args: other_args,
+                },
+            ) if base.same_as(other_base) => {
+                if let (Some((args, ret)), Some((other_args, other_ret))) =
+                    (self.proc_sig(), other.proc_sig())
+                {
+                    // a proc can stand in for another if it accepts all of the other's arguments
+                    args.len() == other_args.len()
+                        && other_args.iter().zip(&args).all(|(a, b)| a.is_assignable_to(b))
+                        && ret.is_assignable_to(&other_ret)
+                        && self.proc_rest() == other.proc_rest()
+                } else {
+                    zip_type_args(type_args, other_args, Self::is_assignable_to)
+                }
+            }
+            // an instance of a class can be used as an instance of the classes it inherits from
+            (Self::Type { .. }, Self::Type { .. }) => self
+                .parents()
+                .iter()
+                .any(|parent| parent.is_assignable_to(other)),
+            _ => false,
+        }
+    }
+
+    /// Returns the type that values of both types can be assigned to, if there is one
+    #[must_use]
+    pub fn unify(self: &Arc<Self>, other: &Arc<Self>) -> Option<Arc<Self>> {
+        if other.is_assignable_to(self) {
+            Some(Arc::clone(self))
+        } else if self.is_assignable_to(other) {
+            Some(Arc::clone(other))
+        } else {
+            // two variants of an enum unify to the enum
+            let enum_ty = self.parent_enum()?;
+            other.is_assignable_to(&enum_ty).then_some(enum_ty)
+        }
+    }
+
+    /// Returns the part of this type that is, or is not if `keep` is false, assignable to `ty`.
+    /// This is what `istype` and `isnttype` narrow a variable to.
+    #[must_use]
+    pub fn narrow(self: &Arc<Self>, ty: &Arc<Self>, keep: bool) -> Arc<Self> {
+        match &**self {
+            Self::Any if keep => Arc::clone(ty),
+            Self::Union(tys) | Self::Enum(tys) => {
+                let members = tys
+                    .iter()
+                    .filter(|member| member.is_assignable_to(ty) == keep)
+                    .cloned()
+                    .collect_vec();
+                if members.len() == tys.len() {
+                    Arc::clone(self)
+                } else {
+                    Self::union(members)
+                }
+            }
+            _ => Arc::clone(self),
+        }
+    }
+
+    /// Replaces the type parameters in the type with the given type arguments
+    #[must_use]
+    pub fn substitute(self: &Arc<Self>, args: &[(SmolStr, TypeArg)]) -> Arc<Self> {
+        match &**self {
+            Self::Param(name) => args
+                .iter()
+                .find_map(|(k, v)| match v {
+                    Either::Right(Either::Right(ty)) if k == name => Some(Arc::clone(ty)),
+                    _ => None,
+                })
+                .unwrap_or_else(|| Arc::clone(self)),
+            Self::Generic { type_args, base } => {
+                let new_args = type_args
+                    .iter()
+                    .map(|(k, v)| {
+                        let v = match v {
+                            // an unbound value parameter, like the length of `arr`
+                            Either::Left(Value::Unit) => args
+                                .iter()
+                                .find(|(k2, v2)| k2 == k && v2.is_left())
+                                .map_or_else(|| v.to_owned(), |(_, v2)| v2.to_owned()),
+                            Either::Left(v) => Either::Left(v.to_owned()),
+                            // a parameter bound to a list of types is spliced into the list
+                            Either::Right(Either::Left(tys)) => Either::Right(Either::Left(
+                                tys.iter()
+                                    .flat_map(|ty| match &**ty {
+                                        Self::Param(name) => args
+                                            .iter()
+                                            .find_map(|(k, v)| match v {
+                                                Either::Right(Either::Left(tys)) if k == name => {
+                                                    Some(tys.to_owned())
+                                                }
+                                                _ => None,
+                                            })
+                                            .unwrap_or_else(|| vec![ty.substitute(args)]),
+                                        _ => vec![ty.substitute(args)],
+                                    })
+                                    .collect(),
+                            )),
+                            Either::Right(Either::Right(ty)) => {
+                                Either::Right(Either::Right(ty.substitute(args)))
+                            }
+                        };
+                        (k.to_owned(), v)
+                    })
+                    .collect_vec();
+                if zip_type_args(&new_args, type_args, |a, b| a.same_as(b)) {
+                    Arc::clone(self)
+                } else {
+                    Self::generic(base, new_args)
+                }
+            }
+            Self::Union(tys) => Self::union(tys.iter().map(|ty| ty.substitute(args)).collect_vec()),
+            Self::Any | Self::Type { .. } | Self::Enum(_) => Arc::clone(self),
+        }
+    }
+
+    /// Binds the type parameters in this type to the matching parts of `actual`.
+    /// Parameters that are already bound in `args` are left as they are.
+    pub fn infer(self: &Arc<Self>, actual: &Arc<Self>, args: &mut Vec<(SmolStr, TypeArg)>) {
+        match (&**self, &**actual) {
+            (Self::Param(name), _) => {
+                if !args.iter().any(|(k, _)| k == name) {
+                    args.push((
+                        name.to_owned(),
+                        Either::Right(Either::Right(Arc::clone(actual))),
+                    ));
+                }
+            }
+            (
+                Self::Generic { base, type_args },
+                Self::Generic {
+                    base: actual_base,
+                    type_args: actual_args,
+                },
+            ) if base.same_as(actual_base) => {
+                for ((_, v1), (_, v2)) in type_args.iter().zip(actual_args) {
+                    match (v1, v2) {
+                        (Either::Right(Either::Left(tys1)), Either::Right(Either::Left(tys2))) => {
+                            for (ty1, ty2) in tys1.iter().zip(tys2) {
+                                ty1.infer(ty2, args);
+                            }
+                        }
+                        (Either::Right(Either::Right(ty1)), Either::Right(Either::Right(ty2))) => {
+                            ty1.infer(ty2, args);
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns the names of the type parameters in the type
+    #[must_use]
+    pub fn params(&self) -> Vec<SmolStr> {
+        match self {
+            Self::Param(name) => vec![name.to_owned()],
+            Self::Generic { type_args, .. } => type_args
+                .iter()
+                .flat_map(|(_, v)| match v {
+                    Either::Left(_) => vec![],
+                    Either::Right(Either::Left(tys)) => tys.iter().flat_map(|ty| ty.params()).collect(),
+                    Either::Right(Either::Right(ty)) => ty.params(),
+                })
+                .unique()
+                .collect(),
+            Self::Union(tys) => tys.iter().flat_map(|ty| ty.params()).unique().collect(),
+            Self::Any | Self::Type { .. } | Self::Enum(_) => vec![],
+        }
+    }
+
+    /// Returns the argument types and return type of a proc type
+    #[must_use]
+    pub fn proc_sig(&self) -> Option<(Vec<Arc<Self>>, Arc<Self>)> {
+        let Self::Generic { type_args, base } = self else {
+            return None;
+        };
+        if !Arc::ptr_eq(base, &PROC_T) {
+            return None;
+        }
+        let (_, Either::Right(Either::Left(arg_tys))) = type_args.iter().find(|(k, _)| *k == "A")? else {
+            unreachable!()
+        };
+        let (_, Either::Right(Either::Right(ret_ty))) = type_args.iter().find(|(k, _)| *k == "R")? else {
+            unreachable!()
+        };
+        Some((arg_tys.to_owned(), Arc::clone(ret_ty)))
+    }
+
+    /// Returns the type of the arguments that a proc type takes after its fixed ones, if it takes
+    /// any number of them: either the type of each, or a list of the type parameter bound to all
+    /// of their types
+    #[must_use]
+    pub fn proc_rest(&self) -> Option<&TypeArg> {
+        let Self::Generic { type_args, base } = self else {
+            return None;
+        };
+        if !Arc::ptr_eq(base, &PROC_T) {
+            return None;
+        }
+        type_args
+            .iter()
+            .find_map(|(k, v)| (*k == "Rest").then_some(v))
+    }
+
+    /// Returns the variant of an enum with the given name, which is `A` for a variant named `E::A`
+    #[must_use]
+    pub fn variant(&self, name: &str) -> Option<&Arc<Self>> {
+        let Self::Enum(tys) = self else {
+            return None;
+        };
+        tys.iter().find(|ty| {
+            matches!(
+                &***ty,
+                Self::Type { name: Some(n), .. } if n.name.rsplit("::").next() == Some(name)
+            )
+        })
+    }
+
     pub fn update_name(self: &mut Arc<Self>, new_name: Ident) -> ZResult<()> {
         let Self::Type { name, .. } = &**self else {
             return Ok(())
@@ -139,6 +632,7 @@ impl ValueType {
                     namespace,
                     fields,
                     type_args,
+                    parents,
                 } => Type::Type {
                     name: name.to_owned(),
                     namespace: namespace
@@ -150,24 +644,85 @@ impl ValueType {
                         .iter()
                         .map(|(k, v)| (k.to_owned(), LazyType::new_lazy(v.to_owned(), Value::ty)))
                         .collect(),
+                    parents: parents.iter().map(Self::to_type).collect(),
+                    variant_of: EnumLink::default(),
                 },
+                Self::Union(tys) => return Type::union(tys.iter().map(Self::to_type).collect_vec()),
             })
         }
     }
     #[must_use]
     pub fn namespace(&self) -> Cow<HashMap<SmolStr, Value>> {
         match self {
-            Self::Any => Cow::Owned(HashMap::new()),
+            Self::Any | Self::Union(_) => Cow::Owned(HashMap::new()),
             Self::Type { namespace, .. } => Cow::Borrowed(namespace),
         }
     }
+    /// Returns the variant of an enum with the given name, which is `A` for a variant named `E::A`
+    #[must_use]
+    pub fn variant(&self, name: &str) -> Option<&Arc<Self>> {
+        let Self::Union(tys) = self else {
+            return None;
+        };
+        tys.iter().find(|ty| {
+            matches!(
+                &***ty,
+                Self::Type { name: Some(n), .. } if n.name.rsplit("::").next() == Some(name)
+            )
+        })
+    }
+    /// Returns the union of `tys`, flattening nested unions
+    #[must_use]
+    pub fn union(tys: impl IntoIterator<Item = Arc<Self>>) -> Arc<Self> {
+        let mut members: Vec<Arc<Self>> = vec![];
+        for ty in tys {
+            let flattened = match &*ty {
+                Self::Any => return Arc::clone(&ANY_T_VAL),
+                Self::Union(tys) => tys.to_owned(),
+                Self::Type { .. } => vec![ty],
+            };
+            for ty in flattened {
+                if !members.contains(&ty) {
+                    members.push(ty);
+                }
+            }
+        }
+        if members.len() == 1 {
+            members.remove(0)
+        } else {
+            Arc::new(Self::Union(members))
+        }
+    }
+    /// Returns the classes that the class directly inherits from
+    #[must_use]
+    pub fn parents(&self) -> &[Arc<Self>] {
+        match self {
+            Self::Type { parents, .. } => parents,
+            _ => &[],
+        }
+    }
+    /// Whether a value of this type is also a value of type `other`
+    #[must_use]
+    pub fn is_subtype_of(self: &Arc<Self>, other: &Arc<Self>) -> bool {
+        match &**other {
+            Self::Any => true,
+            Self::Union(tys) => tys.iter().any(|ty| self.is_subtype_of(ty)),
+            Self::Type { .. } => {
+                Arc::ptr_eq(self, other)
+                    || self
+                        .parents()
+                        .iter()
+                        .any(|parent| parent.is_subtype_of(other))
+            }
+        }
+    }
 }
 
 impl Debug for Type {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "ty {{{self} ")?;
         match self {
-            Self::Any => {}
+            Self::Any | Self::Param(_) | Self::Union(_) | Self::Enum(_) => {}
             Self::Type {
                 name,
                 namespace,
@@ -204,6 +759,8 @@ impl Display for Type {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Any => write!(f, "_any"),
+            Self::Union(tys) | Self::Enum(tys) => write!(f, "{}", tys.iter().join(" / ")),
+            Self::Param(name) => write!(f, "{name}"),
             Self::Type {
                 name, type_args, ..
             } => {
@@ -261,6 +818,7 @@ impl Display for ValueType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Any => write!(f, "_any"),
+            Self::Union(tys) => write!(f, "{}", tys.iter().join(" / ")),
             Self::Type {
                 name, type_args, ..
             } => {
@@ -307,6 +865,8 @@ impl From<BuiltinType> for Type {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            parents: vec![],
+            variant_of: EnumLink::default(),
         }
     }
 }
@@ -321,6 +881,7 @@ impl From<BuiltinType> for ValueType {
                 .into_iter()
                 .map(|(k, _)| (k, Value::Type(Arc::clone(&ANY_T_VAL))))
                 .collect(),
+            parents: vec![],
         }
     }
 }
@@ -357,6 +918,21 @@ impl TypeCheckType {
             Self::Type(_) => Err(ZError::t016()),
         }
     }
+    /// Checks that a branch of an `if`/`match` has a type compatible with the branches before it,
+    /// widening the type of the whole expression if needed
+    pub fn check_branch(first_ty: &mut Option<Self>, ty: Self, span: impl GetSpan) -> ZResult<()> {
+        if let Some(first_ty) = first_ty {
+            let Some(unified) = first_ty.unify(&ty) else {
+                return Err(ZError::t011(first_ty, &ty).with_span(span));
+            };
+            if !Arc::ptr_eq(&unified, first_ty) {
+                *first_ty = ty;
+            }
+        } else {
+            *first_ty = Some(ty);
+        }
+        Ok(())
+    }
 }
 impl From<Arc<Type>> for TypeCheckType {
     fn from(value: Arc<Type>) -> Self {
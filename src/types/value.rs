@@ -2,13 +2,17 @@ use std::{
     borrow::Cow,
     collections::HashMap,
     fmt::{Debug, Display, Formatter},
-    sync::Arc,
+    ops::{Deref, DerefMut, Range},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use enum_as_inner::EnumAsInner;
 use half::f16;
 use itertools::Itertools;
-use num::{BigInt, BigUint};
+use num::{BigInt, BigUint, Complex};
 use smol_str::SmolStr;
 
 use crate::{
@@ -22,7 +26,7 @@ use crate::{
     },
 };
 
-pub type BuiltinFunction = dyn Fn(&Vec<Value>) -> Option<Value> + Send + Sync;
+pub type BuiltinFunction = dyn Fn(&mut Vec<Value>) -> Option<Value> + Send + Sync;
 
 #[derive(Clone)]
 pub enum Proc {
@@ -30,11 +34,20 @@ pub enum Proc {
         f: Arc<BuiltinFunction>,
         id: usize,
         ty: LazyGenericProc,
+        /// Whether the proc takes `&$`, in which case it returns a `tup` of the updated receiver
+        /// and its result, and the caller writes the receiver back
+        updates_receiver: bool,
+        /// The error raised when `f` returns `None`
+        fail_err: fn(&[Value]) -> ZError,
     },
     Defined {
         is_fn: bool,
+        label: Option<SmolStr>,
         content: Block,
         args: Vec<SmolStr>,
+        /// Whether the proc takes `&$`, in which case it returns a `tup` of the updated receiver
+        /// and its result, like a builtin that does
+        updates_receiver: bool,
     },
 }
 impl PartialEq for Proc {
@@ -44,43 +57,97 @@ impl PartialEq for Proc {
             (
                 Self::Defined {
                     is_fn: is_fn1,
+                    label: label1,
                     content: content1,
                     args: args1,
+                    updates_receiver: updates_receiver1,
                 },
                 Self::Defined {
                     is_fn: is_fn2,
+                    label: label2,
                     content: content2,
                     args: args2,
+                    updates_receiver: updates_receiver2,
                 },
-            ) => is_fn1 == is_fn2 && content1 == content2 && args1 == args2,
+            ) => {
+                is_fn1 == is_fn2
+                    && label1 == label2
+                    && content1 == content2
+                    && args1 == args2
+                    && updates_receiver1 == updates_receiver2
+            }
             _ => false,
         }
     }
 }
 
 impl Proc {
-    pub fn call(&self, vals: Vec<Value>, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
+    /// The `_new` of a type whose instances are built from a value for each of `fields`, in
+    /// order. Like every `_new`, it is passed the type being constructed first.
+    #[must_use]
+    pub fn ctor(fields: Vec<SmolStr>) -> Self {
+        let f: Arc<BuiltinFunction> = Arc::new(move |x: &mut Vec<Value>| {
+            let (ty, args) = x.split_first()?;
+            Some(Value::ClassInstance {
+                ty: Arc::clone(ty.as_type()?),
+                attrs: fields
+                    .iter()
+                    .map(ToString::to_string)
+                    .zip(args.iter().cloned())
+                    .collect(),
+            })
+        });
+        Self::Builtin {
+            id: Arc::as_ptr(&f) as *const () as usize,
+            f,
+            ty: LazyGenericProc::new(vec![&TYPE_T], &ANY_T),
+            updates_receiver: false,
+            fail_err: ZError::i001,
+        }
+    }
+    /// Whether the proc is a method taking `$` or `&$`
+    #[must_use]
+    pub fn has_receiver(&self) -> bool {
+        matches!(self, Self::Defined { args, .. } if args.first().is_some_and(|arg| arg == "$"))
+    }
+    pub fn call(&self, mut vals: Vec<Value>, val_symt: &mut InterpretSymTable) -> ZResult<Value> {
         match self {
-            Self::Builtin { f, .. } => {
-                let Some(res) = (*f)(&vals) else {
-                    return Err(ZError::i001(&vals))
+            Self::Builtin { f, fail_err, .. } => {
+                let Some(res) = (*f)(&mut vals) else {
+                    return Err(fail_err(&vals))
                 };
                 Ok(res)
             }
             Self::Defined {
                 content,
                 is_fn,
+                label,
                 args,
+                updates_receiver,
             } => {
-                val_symt.add_frame(if *is_fn {
-                    InterpretFrameType::Function
-                } else {
-                    InterpretFrameType::Normal
-                });
+                val_symt
+                    .add_frame(if *is_fn {
+                        InterpretFrameType::Function
+                    } else {
+                        InterpretFrameType::Normal
+                    })
+                    .label = label.to_owned();
+                if let Some(receiver) = vals.first().filter(|_| self.has_receiver()) {
+                    val_symt.declare_val("$ty", Value::Type(receiver.value_ty()));
+                }
                 for (name, val) in args.iter().zip_eq(vals) {
                     val_symt.declare_val(name, val);
                 }
                 let res = content.interpret_block(val_symt, true, false);
+                let res = if *updates_receiver {
+                    res.and_then(|res| {
+                        Ok(Value::Tup(
+                            vec![val_symt.get_val("$", content)?, res].into(),
+                        ))
+                    })
+                } else {
+                    res
+                };
                 val_symt.pop_frame()?;
                 res
             }
@@ -107,16 +174,87 @@ pub enum Value {
     F16(f16),
     F32(f32),
     F64(f64),
+    Cpx(Box<Complex<Self>>),
+    Frac(Box<Fraction>),
     Str(String),
+    Char(char),
     Bool(bool),
+    Range(Box<Range<Self>>),
+    /// The items of a `vec` or `arr` being iterated over, and the index of the next one
+    Iter(Arc<[Self]>, usize),
+    Opt(Option<Box<Self>>),
+    Res(Result<Box<Self>, Box<Self>>),
+    Arr(Identified<Vec<Self>>),
+    Vec(Identified<Vec<Self>>),
+    Tup(Identified<Vec<Self>>),
+    Hmap(Identified<HmapEntries>),
+    Hset(Identified<HsetItems>),
     Type(Arc<ValueType>),
     Proc(Proc),
     ClassInstance {
         ty: Arc<ValueType>,
-        attrs: HashMap<String, Value>,
+        attrs: Identified<HashMap<String, Value>>,
     },
     Unit,
-    Return(Box<Value>),
+    Return(Box<Self>, Option<SmolStr>),
+    Break(Option<SmolStr>),
+    Continue(Option<SmolStr>),
+}
+
+/// The contents of a collection or class instance, with the identity that `is` compares.
+/// A copy has the same identity until either of them is changed, which gives it a new one
+#[derive(Clone, Debug)]
+pub struct Identified<T> {
+    id: usize,
+    inner: T,
+}
+impl<T> Identified<T> {
+    fn next_id() -> usize {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    }
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T> From<T> for Identified<T> {
+    fn from(inner: T) -> Self {
+        Self {
+            id: Self::next_id(),
+            inner,
+        }
+    }
+}
+impl<A, T: FromIterator<A>> FromIterator<A> for Identified<T> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        T::from_iter(iter).into()
+    }
+}
+impl<T: Default> Default for Identified<T> {
+    fn default() -> Self {
+        T::default().into()
+    }
+}
+impl<T: PartialEq> PartialEq for Identified<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+impl<T> Deref for Identified<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+impl<T> DerefMut for Identified<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.id = Self::next_id();
+        &mut self.inner
+    }
 }
 
 pub trait ValueInner: TryFrom<Value> + Into<Value> + 'static {}
@@ -161,10 +299,47 @@ from_to!(F16, f16, F16_T);
 from_to!(F32, f32, F32_T);
 from_to!(F64, f64, F64_T);
 from_to!(Str, String, STR_T);
+from_to!(Char, char, CHAR_T);
 from_to!(Bool, bool, BOOL_T);
 from_to!(Type, Arc<ValueType>, TYPE_T);
 from_to!(Proc, Proc, PROC_T);
 
+impl From<Complex<Self>> for Value {
+    fn from(value: Complex<Self>) -> Self {
+        Self::Cpx(Box::new(value))
+    }
+}
+impl TryFrom<Value> for Complex<Value> {
+    type Error = ZError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::Cpx(v) = value {
+            Ok(*v)
+        } else {
+            Err(ZError::t011(&CPX_T, &value.ty()))
+        }
+    }
+}
+impl ValueInner for Complex<Value> {}
+
+impl From<Fraction> for Value {
+    fn from(value: Fraction) -> Self {
+        Self::Frac(Box::new(value))
+    }
+}
+impl TryFrom<Value> for Fraction {
+    type Error = ZError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::Frac(v) = value {
+            Ok(*v)
+        } else {
+            Err(ZError::t011(&FRAC_T, &value.ty()))
+        }
+    }
+}
+impl ValueInner for Fraction {}
+
 impl From<()> for Value {
     fn from(_: ()) -> Self {
         Self::Unit
@@ -189,7 +364,7 @@ impl Debug for Proc {
 }
 impl Debug for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Self::Return(v) = self {
+        if let Self::Return(v, _) = self {
             return Debug::fmt(&v, f);
         }
         write!(
@@ -213,11 +388,33 @@ impl Debug for Value {
                 Self::F16(v) => format!("{v}@f16"),
                 Self::F32(v) => format!("{v}@f32"),
                 Self::F64(v) => format!("{v}@f64"),
+                Self::Cpx(v) => format!("cpx({:?}, {:?})", v.re, v.im),
+                Self::Frac(v) => format!("frac({:?}, {:?})", v.num, v.den),
                 Self::Str(v) => format!("\"{v}\""),
+                Self::Char(v) => format!("c\"{v}\""),
                 Self::Type(v) => format!("{v:?}"),
-                Self::Bool(_) | Self::ClassInstance { .. } | Self::Proc { .. } | Self::Unit =>
-                    self.to_string(),
-                Self::Return(_) => unreachable!(),
+                Self::Opt(Some(v)) => format!("{v:?}"),
+                Self::Res(Ok(v)) => format!("ok({v:?})"),
+                Self::Res(Err(v)) => format!("err({v:?})"),
+                Self::Arr(v) | Self::Vec(v) =>
+                    format!("[{}]", v.iter().map(|v| format!("{v:?}")).join(", ")),
+                Self::Tup(v) => format!("({})", v.iter().map(|v| format!("{v:?}")).join(", ")),
+                Self::Hmap(v) => format!(
+                    "{{{}}}",
+                    v.values().flatten().map(|(k, v)| format!("{k:?}: {v:?}")).join(", ")
+                ),
+                Self::Hset(v) =>
+                    format!("{{{}}}", v.values().flatten().map(|v| format!("{v:?}")).join(", ")),
+                Self::Bool(_)
+                | Self::Range(_)
+                | Self::Iter(..)
+                | Self::Opt(None)
+                | Self::ClassInstance { .. }
+                | Self::Proc { .. }
+                | Self::Unit
+                | Self::Break(_)
+                | Self::Continue(_) => self.to_string(),
+                Self::Return(..) => unreachable!(),
             }
         )
     }
@@ -263,17 +460,61 @@ impl Display for Value {
                 Self::F16(v) => Cow::Owned(v.to_string()),
                 Self::F32(v) => Cow::Borrowed(rb.format(*v)),
                 Self::F64(v) => Cow::Borrowed(rb.format(*v)),
+                Self::Cpx(v) => {
+                    let im = v.im.to_string();
+                    Cow::Owned(if im.starts_with('-') {
+                        format!("{}{im}i", v.re)
+                    } else {
+                        format!("{}+{im}i", v.re)
+                    })
+                }
+                Self::Frac(v) => Cow::Owned(format!("{}/{}", v.num, v.den)),
                 Self::Str(v) => Cow::Borrowed(&**v),
+                Self::Char(v) => Cow::Owned(v.to_string()),
                 Self::Bool(v) => Cow::Owned(v.to_string()),
+                Self::Range(v) => Cow::Owned(format!("{}..{}", v.start, v.end)),
+                Self::Iter(items, i) => Cow::Owned(format!(
+                    "iter[{}]",
+                    items.get(*i..).unwrap_or_default().iter().join(", ")
+                )),
+                Self::Opt(v) => v
+                    .as_ref()
+                    .map_or(Cow::Borrowed("null"), |v| Cow::Owned(v.to_string())),
+                Self::Res(Ok(v)) => Cow::Owned(format!("ok({v})")),
+                Self::Res(Err(v)) => Cow::Owned(format!("err({v})")),
+                Self::Arr(v) | Self::Vec(v) => Cow::Owned(format!("[{}]", v.iter().join(", "))),
+                Self::Tup(v) => Cow::Owned(format!("({})", v.iter().join(", "))),
+                Self::Hmap(v) => Cow::Owned(format!(
+                    "{{{}}}",
+                    v.values().flatten().map(|(k, v)| format!("{k}: {v}")).join(", ")
+                )),
+                Self::Hset(v) => Cow::Owned(format!("{{{}}}", v.values().flatten().join(", "))),
                 Self::Type(v) | Self::ClassInstance { ty: v, .. } => Cow::Owned(format!("<{v}>")),
                 Self::Unit => Cow::Borrowed("()"),
-                Self::Return(v) => Cow::Owned(v.to_string()),
+                Self::Return(v, _) => Cow::Owned(v.to_string()),
+                Self::Break(label) => Cow::Owned(
+                    label.as_ref().map_or_else(|| "break".into(), |l| format!("break'{l}")),
+                ),
+                Self::Continue(label) => Cow::Owned(
+                    label
+                        .as_ref()
+                        .map_or_else(|| "continue".into(), |l| format!("continue'{l}")),
+                ),
                 Self::Proc(v) => Cow::Owned(v.to_string()),
             }
         )
     }
 }
 
+/// The type of the items of a sequence, which is `_any` if there are none
+fn item_ty(items: &[Value]) -> Arc<Type> {
+    if items.is_empty() {
+        Arc::clone(&ANY_T)
+    } else {
+        Type::union(items.iter().map(Value::ty).collect_vec())
+    }
+}
+
 impl Value {
     #[must_use]
     pub const fn is_num(&self) -> bool {
@@ -296,9 +537,28 @@ impl Value {
                 | Self::F16(_)
                 | Self::F32(_)
                 | Self::F64(_)
+                | Self::Cpx(_)
+                | Self::Frac(_)
                 | Self::Bool(_)
         )
     }
+    /// Whether `self is other`: types are compared by reference, collections and class instances
+    /// by their identity, and other values, which have none, by value
+    #[must_use]
+    pub fn is_identical(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Type(ty1), Self::Type(ty2)) => Arc::ptr_eq(ty1, ty2),
+            (Self::Arr(v1), Self::Arr(v2))
+            | (Self::Vec(v1), Self::Vec(v2))
+            | (Self::Tup(v1), Self::Tup(v2)) => v1.id() == v2.id(),
+            (Self::Hmap(m1), Self::Hmap(m2)) => m1.id() == m2.id(),
+            (Self::Hset(s1), Self::Hset(s2)) => s1.id() == s2.id(),
+            (Self::ClassInstance { attrs: a1, .. }, Self::ClassInstance { attrs: a2, .. }) => {
+                a1.id() == a2.id()
+            }
+            _ => self == other,
+        }
+    }
     pub fn ty(&self) -> Arc<Type> {
         match self {
             Self::I8(..) => Arc::clone(&I8_T),
@@ -318,16 +578,32 @@ impl Value {
             Self::F16(..) => Arc::clone(&F16_T),
             Self::F32(..) => Arc::clone(&F32_T),
             Self::F64(..) => Arc::clone(&F64_T),
+            Self::Cpx(v) => generic_cpx(v.re.ty()),
+            Self::Frac(v) => generic_frac(v.num.ty()),
             Self::Str(..) => Arc::clone(&STR_T),
+            Self::Char(..) => Arc::clone(&CHAR_T),
             Self::Bool(..) => Arc::clone(&BOOL_T),
+            Self::Range(v) => generic_range(v.start.ty()),
+            Self::Iter(items, _) => generic_iter(item_ty(items)),
+            Self::Opt(v) => generic_opt(v.as_ref().map_or_else(|| Arc::clone(&ANY_T), |v| v.ty())),
+            Self::Res(Ok(v)) => generic_res(v.ty(), Arc::clone(&ANY_T)),
+            Self::Res(Err(v)) => generic_res(Arc::clone(&ANY_T), v.ty()),
+            Self::Arr(v) => generic_arr(item_ty(v), v.len()),
+            Self::Vec(v) => generic_vec(item_ty(v)),
+            Self::Tup(v) => generic_tup(v.iter().map(Self::ty).collect()),
+            Self::Hmap(v) => {
+                let (keys, values): (Vec<_>, Vec<_>) = v.values().flatten().cloned().unzip();
+                generic_hmap(item_ty(&keys), item_ty(&values))
+            }
+            Self::Hset(v) => generic_hset(item_ty(&v.values().flatten().cloned().collect_vec())),
             Self::Type(..) => Arc::clone(&TYPE_T),
             Self::Proc(proc) => Arc::clone(match proc {
                 Proc::Builtin { ty, .. } => ty,
                 Proc::Defined { .. } => &PROC_T,
             }),
             Self::ClassInstance { ty, .. } => ty.to_type(),
-            Self::Unit => Arc::clone(&UNIT_T),
-            Self::Return(v) => v.ty(),
+            Self::Unit | Self::Break(_) | Self::Continue(_) => Arc::clone(&UNIT_T),
+            Self::Return(v, _) => v.ty(),
         }
     }
     pub fn type_check_ty(&self) -> TypeCheckType {
@@ -355,13 +631,25 @@ impl Value {
             Self::F16(..) => Arc::clone(&F16_T_VAL),
             Self::F32(..) => Arc::clone(&F32_T_VAL),
             Self::F64(..) => Arc::clone(&F64_T_VAL),
+            Self::Cpx(..) => Arc::clone(&CPX_T_VAL),
+            Self::Frac(..) => Arc::clone(&FRAC_T_VAL),
             Self::Str(..) => Arc::clone(&STR_T_VAL),
+            Self::Char(..) => Arc::clone(&CHAR_T_VAL),
             Self::Bool(..) => Arc::clone(&BOOL_T_VAL),
+            Self::Range(..) => Arc::clone(&RANGE_T_VAL),
+            Self::Iter(..) => Arc::clone(&ITER_T_VAL),
+            Self::Opt(..) => Arc::clone(&OPT_T_VAL),
+            Self::Res(..) => Arc::clone(&RES_T_VAL),
+            Self::Arr(..) => Arc::clone(&ARR_T_VAL),
+            Self::Vec(..) => Arc::clone(&VEC_T_VAL),
+            Self::Tup(..) => Arc::clone(&TUP_T_VAL),
+            Self::Hmap(..) => Arc::clone(&HMAP_T_VAL),
+            Self::Hset(..) => Arc::clone(&HSET_T_VAL),
             Self::Type(..) => Arc::clone(&TYPE_T_VAL),
             Self::Proc(_) => Arc::clone(&PROC_T_VAL),
             Self::ClassInstance { ty, .. } => Arc::clone(ty),
-            Self::Unit => Arc::clone(&UNIT_T_VAL),
-            Self::Return(v) => v.value_ty(),
+            Self::Unit | Self::Break(_) | Self::Continue(_) => Arc::clone(&UNIT_T_VAL),
+            Self::Return(v, _) => v.value_ty(),
         }
     }
     #[must_use]
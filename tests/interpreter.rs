@@ -0,0 +1,792 @@
+use itertools::Either;
+use pretty_assertions::assert_eq;
+use zyxt::types::sym_table::{InterpretSymTable, TypeCheckSymTable};
+
+/// Compiles and interprets a program, returning its exit code, which is the last value in it
+macro_rules! run {
+    ($str:expr) => {{
+        let mut ty_symt = TypeCheckSymTable::default();
+        let mut val_symt = InterpretSymTable::default();
+        zyxt::compile(
+            &Either::Right(("".into(), $str.to_owned())),
+            &mut ty_symt,
+            true,
+        )
+        .and_then(|compiled| zyxt::interpret(&compiled, &mut val_symt))
+    }};
+}
+
+/// Compiles a program, returning the codes of the warnings it gives
+macro_rules! warnings {
+    ($str:expr) => {{
+        let mut ty_symt = TypeCheckSymTable::default();
+        zyxt::compile(
+            &Either::Right(("".into(), $str.to_owned())),
+            &mut ty_symt,
+            true,
+        )
+        .unwrap();
+        ty_symt
+            .take_warnings()
+            .into_iter()
+            .map(|warning| warning.code)
+            .collect::<Vec<_>>()
+    }};
+}
+
+#[test]
+fn propagate_in_operand() {
+    let f = "f := fn |x: opt[i32]|: opt[i32] { (1 + x?) @ opt[i32] };";
+    assert_eq!(run!(format!("{f} f(2 @ opt[i32])!!")).unwrap(), 3);
+    assert_eq!(run!(format!("{f} f(null) ?: 7")).unwrap(), 7);
+}
+
+#[test]
+fn propagate_in_call_arg() {
+    let f = "id := fn |x: i32|: i32 { x };
+        f := fn |x: opt[i32]|: opt[i32] { id(x? * 2) @ opt[i32] };";
+    assert_eq!(run!(format!("{f} f(2 @ opt[i32])!!")).unwrap(), 4);
+    assert_eq!(run!(format!("{f} f(null) ?: 7")).unwrap(), 7);
+}
+
+#[test]
+fn propagate_in_nested_block() {
+    let f = "f := fn |x: opt[i32]|: opt[i32] { if true { y := x? + 1; }; x };";
+    assert_eq!(run!(format!("{f} f(null) ?: 7")).unwrap(), 7);
+}
+
+#[test]
+fn unwrap_fails() {
+    assert_eq!(run!("(null @ opt[i32])!!").unwrap_err().code, "I002");
+}
+
+#[test]
+fn propagate_err_in_operand() {
+    let f = "R := res[i32, str];
+        f := fn |r: R|: R { R::ok(r!? + 1) };";
+    assert_eq!(run!(format!("{f} f(R::ok(3))!!")).unwrap(), 4);
+    assert_eq!(
+        run!(format!(
+            "{f} if f(R::err(\"e\")) iseq R::err(\"e\") {{ 1 }} else {{ 0 }}"
+        ))
+        .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn propagate_err_in_call_arg() {
+    let f = "id := fn |x: i32|: i32 { x };
+        f := fn |r: res[i32, str]|: res[i32, str] { res[i32, str]::ok(id(r!? * 2)) };";
+    assert_eq!(run!(format!("{f} f(res[i32, str]::ok(3))!!")).unwrap(), 6);
+    assert_eq!(
+        run!(format!("{f} if f(res[i32, str]::err(\"e\")) iseq res[i32, str]::err(\"e\") {{ 1 }} else {{ 0 }}")).unwrap(),
+        1
+    );
+}
+
+#[test]
+fn struct_pattern_child() {
+    assert_eq!(
+        run!(
+            "P := class { inst a: i32 := 4; };
+            C := class { ..P; };
+            p: P := C();
+            match p of P(a: x) { x }"
+        )
+        .unwrap(),
+        4
+    );
+}
+
+#[test]
+fn type_assignability() {
+    // separately instantiated generic types are the same
+    assert_eq!(
+        run!("f := fn |v: vec[i32]|: i32 { v:.len() }; f(vec(1, 2))").unwrap(),
+        2
+    );
+    assert_eq!(
+        run!("f := fn |v: vec[str]|: i32 { v:.len() }; f(vec(1, 2))")
+            .unwrap_err()
+            .code,
+        "T004"
+    );
+    assert_eq!(
+        run!("f := fn |x: i32 / str|: i32 { 1 }; f(3) + f(\"a\")").unwrap(),
+        2
+    );
+    assert_eq!(
+        run!("f := fn |x: i32 / str|: i32 { 1 }; f(true)")
+            .unwrap_err()
+            .code,
+        "T004"
+    );
+    // a class is assignable to the classes it inherits from, but not to one with the same fields
+    let p = "P := class { inst a: i32 := 1; }; f := fn |p: P|: i32 { p.a };";
+    assert_eq!(
+        run!(format!("{p} C := class {{ ..P; }}; f(C())")).unwrap(),
+        1
+    );
+    assert_eq!(
+        run!(format!("{p} Q := class {{ inst a: i32 := 1; }}; f(Q())"))
+            .unwrap_err()
+            .code,
+        "T004"
+    );
+}
+
+#[test]
+fn struct_new_and_fields() {
+    let s = "S := struct |a: i32, b: str| {};";
+    assert_eq!(
+        run!(format!("{s} x := S(1, \"b\"); x.a = x.a + 4; x.a")).unwrap(),
+        5
+    );
+    assert_eq!(run!(format!("{s} x := S(1)")).unwrap_err().code, "T015");
+    assert_eq!(
+        run!(format!("{s} x := S(1, \"b\", 3)")).unwrap_err().code,
+        "T015"
+    );
+    assert_eq!(run!(format!("{s} x := S(1, 2)")).unwrap_err().code, "T004");
+    assert_eq!(
+        run!(format!("{s} x := S(a: 1, b: \"b\")"))
+            .unwrap_err()
+            .code,
+        "T029"
+    );
+    assert_eq!(
+        run!(format!("{s} x := S(1, \"b\"); x.a = \"c\""))
+            .unwrap_err()
+            .code,
+        "T011"
+    );
+    assert_eq!(
+        run!(format!("{s} x := S(1, \"b\"); x.c = 3"))
+            .unwrap_err()
+            .code,
+        "T005"
+    );
+    // a field of a field is set in place
+    assert_eq!(
+        run!(format!(
+            "{s} T := struct |s: S| {{}}; t := T(S(1, \"b\")); t.s.a = 4; t.s.a"
+        ))
+        .unwrap(),
+        4
+    );
+}
+
+#[test]
+fn override_method() {
+    assert_eq!(
+        run!(
+            "P := class { get := fn |$|: i32 { 1 }; };
+            C := class { ..P; get := fn |$|: i32 { 2 }; };
+            f := fn |p: P|: i32 { p:.get() };
+            f(C())"
+        )
+        .unwrap(),
+        2
+    );
+}
+
+#[test]
+fn receiver_methods_update_instance() {
+    let c = "C := class {
+        inst n: i32 := 0;
+        add := fn |&$, k: i32|: i32 { $.n = $.n + k; $.n * 10 };
+        inc := fn |&$| { $.n = $.n + 1; };
+        twice := fn |&$| { $:.inc(); $:.inc(); };
+        reset := fn |$| { $.n = 0; };
+        get := fn |$|: i32 { $.n };
+    };
+    c := C();";
+    assert_eq!(
+        run!(format!("{c} c:.add(2); c:.add(3); c:.get()")).unwrap(),
+        5
+    );
+    // the result of the method is returned along with the instance being updated
+    assert_eq!(run!(format!("{c} r := c:.add(2); r + c.n")).unwrap(), 22);
+    assert_eq!(run!(format!("{c} c:.twice(); c.n")).unwrap(), 2);
+    // `$` is a copy, so changing it does nothing to the instance
+    assert_eq!(run!(format!("{c} c:.inc(); c:.reset(); c.n")).unwrap(), 1);
+    assert_eq!(
+        run!(format!("{c} d := c; d:.inc(); c.n * 10 + d.n")).unwrap(),
+        1
+    );
+}
+
+#[test]
+fn override_mismatch() {
+    for child in [
+        "get := fn |$|: str { \"a\" };",
+        "get := fn |$, x: i32|: i32 { x };",
+        "k := \"a\";",
+        "inst x: str := \"a\";",
+    ] {
+        let program = format!(
+            "P := class {{ inst x: i32 := 0; k := 1; get := fn |$|: i32 {{ 1 }}; }};
+            C := class {{ ..P; {child} }};"
+        );
+        assert_eq!(run!(program).unwrap_err().code, "T035");
+    }
+}
+
+#[test]
+fn inherited_new_with_fields() {
+    assert_eq!(
+        run!(
+            "P := class { inst a: i32 := 1; _new := fn |&$, a: i32| { $.a = a; }; };
+            C := class { ..P; inst b: i32 := 2; };
+            c := C(4);
+            c.a * 10 + c.b"
+        )
+        .unwrap(),
+        42
+    );
+    assert_eq!(
+        run!(
+            "S := struct |a: i32| {};
+            C := class { ..S; inst b: i32 := 3; };
+            c := C(5);
+            c.a * 10 + c.b"
+        )
+        .unwrap(),
+        53
+    );
+}
+
+#[test]
+fn generic_body_checked_per_call() {
+    assert_eq!(
+        run!("max := fn[T] |a: T, b: T|: T { if a > b { a } else { b } }; max(3, 5)").unwrap(),
+        5
+    );
+    assert_eq!(
+        run!("id := fn[T] |a: T|: T { a + \"x\" }; id(3)")
+            .unwrap_err()
+            .code,
+        "T004"
+    );
+}
+
+#[test]
+fn generic_calls_generic() {
+    let id = "id := fn[T] |a: T|: T { a };";
+    assert_eq!(
+        run!(format!(
+            "{id} twice := fn[T] |a: T|: T {{ id(a) + id(a) }}; twice(4)"
+        ))
+        .unwrap(),
+        8
+    );
+    assert_eq!(
+        run!(format!(
+            "{id} twice := fn[T] |a: T|: T {{ id(a) - \"x\" }}; twice(4)"
+        ))
+        .unwrap_err()
+        .code,
+        "T004"
+    );
+    assert_eq!(
+        run!("f := fn: i32 { g := fn[T] |a: T|: T { a * 2 }; g(5) }; f()").unwrap(),
+        10
+    );
+}
+
+#[test]
+fn shadowed_collection_ctor() {
+    assert_eq!(run!("vec := fn |x: i32|: i32 {x}; vec(3) + 1").unwrap(), 4);
+    assert_eq!(run!("set := fn |x: i32|: i32 {x * 2}; set(4)").unwrap(), 8);
+}
+
+#[test]
+fn collection_ctors() {
+    assert_eq!(run!("a := array(1, 2, 3); a[2] + a:.len()").unwrap(), 6);
+    assert_eq!(run!("t := tu(1, \"a\", 3); t[0] + t[2]").unwrap(), 4);
+    assert_eq!(run!("s := set(1, 2, 2); s:.len()").unwrap(), 2);
+    assert_eq!(run!("v := vec(); v:.push(4); v[0]").unwrap(), 4);
+    assert_eq!(run!("v := vec(1, \"a\"); 0").unwrap_err().code, "T004");
+    assert_eq!(
+        run!("m := hmap(1: 2, \"a\": 3); 0").unwrap_err().code,
+        "T004"
+    );
+    assert_eq!(run!("c := cpx(1, 2.0); 0").unwrap_err().code, "T004");
+    assert_eq!(run!("f := frac(1.0, 2.0); 0").unwrap_err().code, "T004");
+    assert_eq!(run!("f := frac(1, 0); 0").unwrap_err().code, "I003");
+    assert_eq!(
+        run!("f := fn |x: i32|: i32 {x}; f(1, a: 2)")
+            .unwrap_err()
+            .code,
+        "T029"
+    );
+}
+
+#[test]
+fn cpx_frac_arithmetic() {
+    for (expr, expected) in [
+        ("cpx(1, 2) + cpx(3, 4)", "cpx(4, 6)"),
+        ("cpx(4, 2) - cpx(1, 1)", "cpx(3, 1)"),
+        ("cpx(1, 2) * cpx(3, 4)", "cpx(-5, 10)"),
+        ("cpx(2, 4) / cpx(1, 1)", "cpx(3, 1)"),
+        ("cpx(1.5, 2.0) * cpx(2.0, 0.0)", "cpx(3.0, 4.0)"),
+        ("frac(1, 2) + frac(1, 3)", "frac(5, 6)"),
+        ("frac(1, 2) - frac(3, 4)", "frac(-1, 4)"),
+        ("frac(1, 2) * frac(2, 3)", "frac(1, 3)"),
+        ("frac(1, 2) / frac(1, 4)", "frac(2, 1)"),
+    ] {
+        assert_eq!(
+            run!(format!("if {expr} iseq {expected} {{ 1 }} else {{ 0 }}")).unwrap(),
+            1,
+            "{expr}"
+        );
+    }
+    assert_eq!(
+        run!("if frac(2, 4) == frac(1, 2) && frac(1, 2) < frac(2, 3) { 1 } else { 0 }").unwrap(),
+        1
+    );
+    assert_eq!(run!("x := cpx(1, 2) + 1").unwrap_err().code, "T004");
+    assert_eq!(
+        run!("x := frac(1, 2) + cpx(1, 2)").unwrap_err().code,
+        "T004"
+    );
+    // the components overflow like the integers they are
+    assert_eq!(
+        run!("x := frac(200u8, 1u8) + frac(100u8, 1u8)")
+            .unwrap_err()
+            .code,
+        "I001"
+    );
+}
+
+#[test]
+fn hmap_expr_keys() {
+    assert_eq!(
+        run!("m := hmap(1: 10, 2 + 1: 30); m[3] + m[1]").unwrap(),
+        40
+    );
+    assert_eq!(run!("k := 5; m := hmap(k: 1); m[\"k\"]").unwrap(), 1);
+    assert_eq!(run!("m := hmap(1: 10); m[\"a\"]").unwrap_err().code, "T004");
+}
+
+#[test]
+fn update_receiver_through_fields() {
+    assert_eq!(
+        run!(
+            "S := class { inst v: vec[i32] := vec(1); };
+            T := class { inst s: S := S(); };
+            t := T();
+            t.s.v:.push(2);
+            t.s.v:.len() * 10 + t.s.v[1]"
+        )
+        .unwrap(),
+        22
+    );
+    assert_eq!(
+        run!("w := vec(vec(1)); w[0]:.push(3); 0").unwrap_err().code,
+        "I005"
+    );
+}
+
+#[test]
+fn extract_opr() {
+    assert_eq!(
+        run!("v := vec(1, 2, 3); x := v >> 1; (x ?: 0) * 10 + v:.len()").unwrap(),
+        22
+    );
+    assert_eq!(
+        run!("v := vec(1); (v >> 4 ?: 7) * 10 + v:.len()").unwrap(),
+        71
+    );
+    assert_eq!(
+        run!("s := set(1, 2); s << 3; if s >> 1 { s:.len() } else { 0 }").unwrap(),
+        2
+    );
+}
+
+#[test]
+fn shift_with_insert_extract() {
+    assert_eq!(run!("(1 << 3) + (64 >> 2)").unwrap(), 24);
+    assert_eq!(run!("-16 >> 2").unwrap(), -4);
+    // looser than `+`, tighter than `\\&` and comparisons
+    assert_eq!(run!("1 + 1 << 2").unwrap(), 8);
+    assert_eq!(run!("1 << 3 \\& 12").unwrap(), 8);
+    assert_eq!(run!("if 1 << 2 < 5 { 1 } else { 0 }").unwrap(), 1);
+    assert_eq!(run!("v := vec(1); v << 1 + 2; v[1]").unwrap(), 3);
+    assert_eq!(run!("x := 1i32 << 40").unwrap_err().code, "I004");
+}
+
+#[test]
+fn is_compares_types() {
+    assert_eq!(
+        run!("x := i32; if x is i32 { if str isnt i32 { 1 } else { 0 } } else { 0 }").unwrap(),
+        1
+    );
+    assert_eq!(run!("if 3 is 3 { 1 } else { 0 }").unwrap(), 1);
+}
+
+#[test]
+fn is_compares_identity() {
+    let p = "P := class { inst a: i32 := 1; };";
+    assert_eq!(
+        run!(format!(
+            "{p} x := P(); y := P(); if x is y {{ 1 }} else {{ 0 }}"
+        ))
+        .unwrap(),
+        0
+    );
+    assert_eq!(
+        run!(format!(
+            "{p} x := P(); y := x; if x is y && x iseq y {{ 1 }} else {{ 0 }}"
+        ))
+        .unwrap(),
+        1
+    );
+    assert_eq!(
+        run!(format!(
+            "{p} x := P(); y := x; y.a = 2; if x isnt y {{ 1 }} else {{ 0 }}"
+        ))
+        .unwrap(),
+        1
+    );
+    assert_eq!(
+        run!("v := vec(1); w := vec(1); if v isnt w && v iseq w { 1 } else { 0 }").unwrap(),
+        1
+    );
+    assert_eq!(
+        run!("v := vec(1); w := v; u := v; u:.push(2); if v is w && v isnt u { 1 } else { 0 }")
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn bitwise_results() {
+    for (expr, expected) in [
+        (r"12 \& 10", 8),
+        (r"12 \| 10", 14),
+        (r"12 \^ 10", 6),
+        (r"\~12", -13),
+        (r"1 \< 4", 16),
+        (r"-16 \> 2", -4),
+        (r"(-16i8 \>> 2i8) @ i32", 60),
+        ("12:.and(10)", 8),
+        ("12:.or(10)", 14),
+        ("12:.xor(10)", 6),
+        ("12:.compl()", -13),
+        ("1:.lsh(4)", 16),
+        ("-16:.rsh(2)", -4),
+        ("-16i8:.zrsh(2i8) @ i32", 60),
+        (r"(\~0u8) @ i32", 255),
+        (r"((5 @ ibig) \& (3 @ ibig)) @ i32", 1),
+        (r"((1 @ ibig) \< (40 @ ibig) \> (38 @ ibig)) @ i32", 4),
+    ] {
+        assert_eq!(run!(expr).unwrap(), expected, "{expr}");
+    }
+    // a negative big integer has no leading bits to fill with zeroes
+    assert_eq!(
+        run!(r"x := (-1 @ ibig) \>> (1 @ ibig)").unwrap_err().code,
+        "I004"
+    );
+}
+
+#[test]
+fn shift_overflow() {
+    for src in ["x := 1;\nx \\< 40", "x := 1;\nx:.lsh(40)"] {
+        let err = run!(src).unwrap_err();
+        assert_eq!(err.code, "I004");
+        assert_eq!(err.pos[0].start_pos.line, 2);
+    }
+    assert_eq!(run!("x := 1; x \\< 31 \\> 31").unwrap(), -1);
+}
+
+#[test]
+fn coverage_bool() {
+    assert_eq!(
+        run!("b := true; match b of true {1} of false {2}").unwrap(),
+        1
+    );
+    assert_eq!(
+        run!("b := true; match b of true {1}").unwrap_err().code,
+        "T021"
+    );
+}
+
+#[test]
+fn coverage_enum() {
+    let e = "E := enum { inst A; inst B; inst C: struct |x: i32| {}; }; e: E := E::C(3);";
+    assert_eq!(
+        run!(format!(
+            "{e} match e of E::A {{1}} of E::B {{2}} of E::C(x: n) {{n}}"
+        ))
+        .unwrap(),
+        3
+    );
+    assert_eq!(
+        run!(format!("{e} match e of E::A {{1}} of E::C(x: n) {{n}}"))
+            .unwrap_err()
+            .code,
+        "T021"
+    );
+}
+
+#[test]
+fn union_of_types() {
+    assert_eq!(
+        run!("T := i32 / str; U := T / bool; u := \"a\" @ U; match u of istype bool {1} of istype i32 {2} of istype str {3}")
+            .unwrap(),
+        3
+    );
+    assert_eq!(run!("x := 8 / 2; x").unwrap(), 4);
+}
+
+#[test]
+fn istype_narrows() {
+    let u = "u := 3 @ (i32 / str);";
+    assert_eq!(
+        run!(format!("{u} if u istype i32 {{ u + 1 }} else {{ 0 }}")).unwrap(),
+        4
+    );
+    // the `else` branch has the other members
+    assert_eq!(
+        run!(format!("{u} if u istype str {{ 0 }} else {{ u * 2 }}")).unwrap(),
+        6
+    );
+    assert_eq!(
+        run!("u := \"ab\" @ (i32 / str); s := if u isnttype i32 { u ~ \"c\" } else { \"\" }; if s == \"abc\" { 1 } else { 0 }")
+            .unwrap(),
+        1
+    );
+    assert_eq!(run!(format!("{u} u + 1")).unwrap_err().code, "T005");
+}
+
+#[test]
+fn coverage_union() {
+    let u = "u := 3 @ (i32 / str);";
+    assert_eq!(
+        run!(format!(
+            "{u} match u of istype i32 {{1}} of istype str {{2}}"
+        ))
+        .unwrap(),
+        1
+    );
+    assert_eq!(
+        run!(format!("{u} match u of istype i32 {{1}}"))
+            .unwrap_err()
+            .code,
+        "T021"
+    );
+    assert_eq!(
+        run!("u := vec(1) @ (vec[i32] / str); match u of istype vec[i32] {1} of istype str {2}")
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn coverage_nested_struct() {
+    let s = "P := struct |x: i32, q: bool| {}; Q := struct |p: P, b: bool| {};
+        v := Q(P(1, true), false);";
+    assert_eq!(
+        run!(format!(
+            "{s} match v of Q(p: P(q: true), b: true) {{1}} of Q(p: P(q: false)) {{2}} \
+             of Q(b: false) {{3}}"
+        ))
+        .unwrap(),
+        3
+    );
+    assert_eq!(
+        run!(format!(
+            "{s} match v of Q(p: P(q: true)) {{1}} of Q(b: true) {{2}}"
+        ))
+        .unwrap_err()
+        .code,
+        "T021"
+    );
+}
+
+#[test]
+fn unreachable_arm() {
+    assert_eq!(warnings!("match 3 of _ {1} of 3 {2}"), ["W001"]);
+    assert_eq!(
+        warnings!("b := true; match b of true {1} of false {2} of true {3}"),
+        ["W001"]
+    );
+    assert_eq!(
+        warnings!("E := enum { inst A; inst B; }; e: E := E::A; match e of E::A {1} of E::B {2}"),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn postfix_gives_old_value() {
+    assert_eq!(run!("x := 3; y := x++; y * 10 + x").unwrap(), 34);
+    assert_eq!(run!("x := 3; y := x--; y * 10 + x").unwrap(), 32);
+}
+
+#[test]
+fn enum_mismatch_and_names() {
+    let enums = "E := enum { inst A; inst B; }; F := enum { inst A; };";
+    assert_eq!(
+        run!(format!("{enums} x: E := F::A; 0")).unwrap_err().code,
+        "T011"
+    );
+    let err = run!(format!("{enums} e: E := E::A; match e of E::A {{1}}")).unwrap_err();
+    assert_eq!(err.code, "T021");
+    assert!(err.message.contains("`E::B`"), "{}", err.message);
+}
+
+#[test]
+fn labelled_return_through_expr() {
+    let g = "g := fn: i32 { ret'outer 9; 1 };";
+    assert_eq!(
+        run!(format!(
+            "f := fn'outer: i32 {{ {g} x := g() + 10; 2 }}; f()"
+        ))
+        .unwrap(),
+        9
+    );
+    assert_eq!(
+        run!(format!(
+            "id := fn |x: i32|: i32 {{ x }}; f := fn'outer: i32 {{ {g} id(g()); 2 }}; f()"
+        ))
+        .unwrap(),
+        9
+    );
+}
+
+#[test]
+fn for_over_ranges() {
+    assert_eq!(run!("s := 0; for i in 0..5 { s += i; }; s").unwrap(), 10);
+    assert_eq!(
+        run!("s := 0i64; for i in 2i64..5i64 { s += i; }; s @ i32").unwrap(),
+        9
+    );
+    assert_eq!(
+        run!("s := 0u8; for i in 1u8..4u8 { s += i; }; s @ i32").unwrap(),
+        6
+    );
+    assert_eq!(run!("s := 0; for i in 3..3 { s += 1; }; s").unwrap(), 0);
+}
+
+#[test]
+fn for_over_vec() {
+    assert_eq!(
+        run!("s := 0; for x in vec(1, 2, 3) { s = s * 10 + x; }; s").unwrap(),
+        123
+    );
+    assert_eq!(
+        run!("s := 0; a := array(4, 5); for x in a { for y in a { s = s * 10 + y; }; }; s")
+            .unwrap(),
+        4545
+    );
+    // the loop goes over the items the vec had when it started
+    assert_eq!(
+        run!("v := vec(1, 2); for x in v { v:.push(x); }; v:.len()").unwrap(),
+        4
+    );
+}
+
+#[test]
+fn in_place_updates() {
+    assert_eq!(
+        run!("v := vec(1, 2); v:.push(3); v << 4; v:.pop(); v:.remove(0); v[0] * 10 + v[1]")
+            .unwrap(),
+        23
+    );
+    assert_eq!(
+        run!("s := set(1); s:.insert(2); s:.insert(2); s:.remove(1); s:.len()").unwrap(),
+        1
+    );
+    assert_eq!(
+        run!("m := hmap(1: 2); m:.insert(3, 4); m:.remove(1); m[3]").unwrap(),
+        4
+    );
+    // a copy passed to a function is updated on its own
+    assert_eq!(
+        run!("v := vec(1); f := fn |w: vec[i32]|: i32 { w:.push(2); w:.len() }; f(v) * 10 + v:.len()")
+            .unwrap(),
+        21
+    );
+}
+
+#[test]
+fn index_out_of_bounds() {
+    let err = run!("v := vec(1, 2);\nv[1 + 4]").unwrap_err();
+    assert_eq!(err.code, "I006");
+    assert_eq!(err.pos[0].start_pos.line, 2);
+    assert_eq!(err.pos[0].start_pos.column, 3);
+    assert_eq!(run!("a := array(1); a[-1]").unwrap_err().code, "I006");
+    assert_eq!(run!("s := \"ab\"; s[2]").unwrap_err().code, "I006");
+}
+
+#[test]
+fn break_and_continue() {
+    assert_eq!(
+        run!("s := 0; for i in 0..10 { if i == 3 { continue }; if i == 6 { break }; s += i; }; s")
+            .unwrap(),
+        12
+    );
+    assert_eq!(
+        run!("s := 0; i := 0; while i < 5 { i += 1; if i == 2 { continue }; s += i; }; s").unwrap(),
+        13
+    );
+}
+
+#[test]
+fn labelled_loops() {
+    assert_eq!(
+        run!(
+            "s := 0;
+            for'o i in 0..3 { for j in 0..3 {
+                if j == 2 { continue'o }; if i == 2 { break'o }; s = s * 10 + j;
+            } };
+            s"
+        )
+        .unwrap(),
+        101
+    );
+    assert_eq!(
+        run!(
+            "s := 0; i := 0;
+            while'o i < 3 { i += 1; for j in 0..3 { if j == 1 { break'o }; s += 1; } };
+            s * 10 + i"
+        )
+        .unwrap(),
+        11
+    );
+}
+
+#[test]
+fn return_from_loop() {
+    assert_eq!(
+        run!("f := fn: i32 { for i in 0..10 { if i == 4 { ret i * 10 } }; 1 }; f()").unwrap(),
+        40
+    );
+}
+
+#[test]
+fn inner_blocks_dont_return() {
+    assert_eq!(
+        run!(
+            "f := fn: i32 {
+                if true { \"a\" } else { \"b\" };
+                x := { 2 };
+                while false { \"c\" };
+                for i in 0..2 { \"d\" };
+                x
+            };
+            f()"
+        )
+        .unwrap(),
+        2
+    );
+    assert_eq!(run!("f := fn: i32 { \"a\" }; 0").unwrap_err().code, "T003");
+    assert_eq!(
+        run!("f := fn: i32 { if true { ret \"a\" }; 1 }; 0")
+            .unwrap_err()
+            .code,
+        "T003"
+    );
+}
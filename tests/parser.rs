@@ -102,6 +102,23 @@ fn bin_opr() {
         ("<=", OprType::Le),
         ("&&", OprType::And),
         ("||", OprType::Or),
+        ("?:", OprType::Coalesce),
+        ("!?:", OprType::Handle),
+        ("istype", OprType::Istype),
+        ("isnttype", OprType::Isnttype),
+        ("is", OprType::Is),
+        ("isnt", OprType::Isnt),
+        ("iseq", OprType::Iseq),
+        ("isnteq", OprType::Isnteq),
+        ("\\&", OprType::BitAnd),
+        ("\\|", OprType::BitOr),
+        ("\\^", OprType::BitXor),
+        ("\\<", OprType::Lsh),
+        ("\\>", OprType::Rsh),
+        ("\\>>", OprType::Zrsh),
+        ("<<", OprType::Insert),
+        (">>", OprType::Extract),
+        ("|>", OprType::Pipe),
     ] {
         let s = format!("x {sy} y");
         let ast = parse!(s);
@@ -117,12 +134,33 @@ fn bin_opr() {
     }
 }
 
+#[test]
+fn pow_right_assoc() {
+    let ast = parse!("x ^ y ^ z");
+    assert_eq!(
+        ast[0],
+        Ast::BinaryOpr(BinaryOpr {
+            ty: OprType::Pow,
+            opr_span: Some(span!(1, 3, "^")),
+            operand1: ident!(1, 1, "x"),
+            operand2: Box::new(Ast::BinaryOpr(BinaryOpr {
+                ty: OprType::Pow,
+                opr_span: Some(span!(1, 7, "^")),
+                operand1: ident!(1, 5, "y"),
+                operand2: ident!(1, 9, "z")
+            }))
+        })
+    )
+}
+
 #[test]
 fn class() {
     let ast = parse!("class { }");
     assert_eq!(
         ast[0],
         Ast::Class(Class::Raw {
+            name: None,
+            kwd_span: None,
             is_struct: false,
             content: Some(Block {
                 brace_spans: None,
@@ -140,6 +178,8 @@ fn struct_params() {
     assert_eq!(
         ast[0],
         Ast::Class(Class::Raw {
+            name: None,
+            kwd_span: None,
             is_struct: true,
             content: Some(Block {
                 brace_spans: None,
@@ -161,6 +201,8 @@ fn struct_no_content() {
     assert_eq!(
         ast[0],
         Ast::Class(Class::Raw {
+            name: None,
+            kwd_span: None,
             is_struct: true,
             content: None,
             args: Some(vec![Argument {
@@ -179,6 +221,8 @@ fn struct_no_params() {
     assert_eq!(
         ast[0],
         Ast::Class(Class::Raw {
+            name: None,
+            kwd_span: None,
             is_struct: true,
             content: Some(Block {
                 brace_spans: None,
@@ -196,6 +240,8 @@ fn struct_no_content_no_params() {
     assert_eq!(
         ast[0],
         Ast::Class(Class::Raw {
+            name: None,
+            kwd_span: None,
             is_struct: true,
             content: None,
             args: None
@@ -452,7 +498,10 @@ fn proc_kwd() {
         Ast::Procedure(Procedure {
             is_fn: false,
             kwd_span: Some(span!(1, 1, "proc")),
+            label: None,
+            type_params: vec![],
             args: vec![],
+            updates_receiver: false,
             return_type: None,
             content: Block {
                 brace_spans: None,
@@ -470,7 +519,10 @@ fn proc_nokwd() {
         Ast::Procedure(Procedure {
             is_fn: false,
             kwd_span: None,
+            label: None,
+            type_params: vec![],
             args: vec![],
+            updates_receiver: false,
             return_type: None,
             content: Block {
                 brace_spans: None,
@@ -488,7 +540,10 @@ fn fn_kwd() {
         Ast::Procedure(Procedure {
             is_fn: true,
             kwd_span: Some(span!(1, 1, "fn")),
+            label: None,
+            type_params: vec![],
             args: vec![],
+            updates_receiver: false,
             return_type: None,
             content: Block {
                 brace_spans: None,
@@ -506,7 +561,10 @@ fn fn_arg() {
         Ast::Procedure(Procedure {
             is_fn: true,
             kwd_span: Some(span!(1, 1, "fn")),
+            label: None,
+            type_params: vec![],
             args: vec![],
+            updates_receiver: false,
             return_type: None,
             content: Block {
                 brace_spans: None,
@@ -516,6 +574,102 @@ fn fn_arg() {
     )
 }
 
+#[test]
+fn fn_type_params() {
+    let ast = parse!("fn[T] |x: T| x");
+    assert_eq!(
+        ast[0],
+        Ast::Procedure(Procedure {
+            is_fn: true,
+            kwd_span: Some(span!(1, 1, "fn")),
+            label: None,
+            type_params: vec![ident!(notvar 1, 4, "T")],
+            args: vec![Argument {
+                name: ident!(notvar 1, 8, "x"),
+                ty: ident!(1, 11, "T"),
+                default: None
+            }],
+            updates_receiver: false,
+            return_type: None,
+            content: Block {
+                brace_spans: None,
+                content: vec![*ident!(1, 14, "x")]
+            }
+        })
+    )
+}
+
+#[test]
+fn fn_receiver() {
+    let ast = parse!("fn |&$, o: $ty| o");
+    assert_eq!(
+        ast[0],
+        Ast::Procedure(Procedure {
+            is_fn: true,
+            kwd_span: Some(span!(1, 1, "fn")),
+            label: None,
+            type_params: vec![],
+            args: vec![
+                Argument {
+                    name: ident!(notvar 1, 6, "$"),
+                    ty: Box::new(Ast::Ident(Ident {
+                        name: "$ty".into(),
+                        name_span: None
+                    })),
+                    default: None
+                },
+                Argument {
+                    name: ident!(notvar 1, 9, "o"),
+                    ty: ident!(1, 12, "$ty"),
+                    default: None
+                }
+            ],
+            updates_receiver: true,
+            return_type: None,
+            content: Block {
+                brace_spans: None,
+                content: vec![*ident!(1, 17, "o")]
+            }
+        })
+    )
+}
+
+#[test]
+fn fn_args_type_args() {
+    let ast = parse!("fn |r: res[i32, str], x: i32| r");
+    assert_eq!(
+        ast[0],
+        Ast::Procedure(Procedure {
+            is_fn: true,
+            kwd_span: Some(span!(1, 1, "fn")),
+            label: None,
+            type_params: vec![],
+            args: vec![
+                Argument {
+                    name: ident!(notvar 1, 5, "r"),
+                    ty: Box::new(Ast::Index(Index {
+                        parent: ident!(1, 8, "res"),
+                        bracket_spans: Some((span!(1, 11, "["), span!(1, 20, "]"))),
+                        args: vec![*ident!(1, 12, "i32"), *ident!(1, 17, "str")]
+                    })),
+                    default: None
+                },
+                Argument {
+                    name: ident!(notvar 1, 23, "x"),
+                    ty: ident!(1, 26, "i32"),
+                    default: None
+                }
+            ],
+            updates_receiver: false,
+            return_type: None,
+            content: Block {
+                brace_spans: None,
+                content: vec![*ident!(1, 31, "r")]
+            }
+        })
+    )
+}
+
 #[test]
 fn return_nothing() {
     let ast = parse!("ret");
@@ -523,6 +677,7 @@ fn return_nothing() {
         ast[0],
         Ast::Return(Return {
             kwd_span: Some(span!(1, 1, "ret")),
+            label: None,
             value: Value::Unit.as_ast().into()
         })
     )
@@ -535,6 +690,7 @@ fn return_something() {
         ast[0],
         Ast::Return(Return {
             kwd_span: Some(span!(1, 1, "ret")),
+            label: None,
             value: ident!(1, 5, "x")
         })
     )
@@ -563,6 +719,34 @@ fn un_opr() {
     }
 }
 
+#[test]
+fn spread() {
+    let ast = parse!("..x");
+    assert_eq!(
+        ast[0],
+        Ast::Spread(Spread {
+            dots_span: Some(span!(1, 1, "..")),
+            content: ident!(1, 3, "x"),
+        })
+    )
+}
+
+#[test]
+fn inc_dec() {
+    for (sy, ty) in [("++", OprType::Inc), ("--", OprType::Dec)] {
+        let s = format!("x{sy}");
+        let ast = parse!(s);
+        assert_eq!(
+            ast[0],
+            Ast::UnaryOpr(UnaryOpr {
+                ty,
+                opr_span: Some(span!(1, 2, sy)),
+                operand: ident!(1, 1, "x"),
+            })
+        )
+    }
+}
+
 #[test]
 fn unparen_call_single() {
     let ast = parse!("x y");
@@ -670,3 +854,372 @@ fn dot_call() {
         })
     )
 }
+
+#[test]
+fn while_() {
+    let ast = parse!("while x { }");
+    assert_eq!(
+        ast[0],
+        Ast::While(While {
+            is_do_while: false,
+            label: None,
+            kwd_span: Some(span!(1, 1, "while")),
+            do_kwd_span: None,
+            condition: ident!(1, 7, "x"),
+            content: Block {
+                brace_spans: Some((span!(1, 9, "{"), span!(1, 11, "}"))),
+                content: vec![]
+            },
+            init: None
+        })
+    )
+}
+
+#[test]
+fn do_while() {
+    let ast = parse!("do { } while x");
+    assert_eq!(
+        ast[0],
+        Ast::While(While {
+            is_do_while: true,
+            label: None,
+            kwd_span: Some(span!(1, 8, "while")),
+            do_kwd_span: Some(span!(1, 1, "do")),
+            condition: ident!(1, 14, "x"),
+            content: Block {
+                brace_spans: Some((span!(1, 4, "{"), span!(1, 6, "}"))),
+                content: vec![]
+            },
+            init: None
+        })
+    )
+}
+
+#[test]
+fn for_() {
+    let ast = parse!("for i in x..y { }");
+    assert_eq!(
+        ast[0],
+        Ast::For(For {
+            kwd_span: Some(span!(1, 1, "for")),
+            label: None,
+            variable: ident!(notvar 1, 5, "i"),
+            in_span: Some(span!(1, 7, "in")),
+            iterable: Box::new(Ast::BinaryOpr(BinaryOpr {
+                ty: OprType::Range,
+                opr_span: Some(span!(1, 11, "..")),
+                operand1: ident!(1, 10, "x"),
+                operand2: ident!(1, 13, "y")
+            })),
+            content: Block {
+                brace_spans: Some((span!(1, 15, "{"), span!(1, 17, "}"))),
+                content: vec![]
+            }
+        })
+    )
+}
+
+#[test]
+fn labelled_while() {
+    let ast = parse!("while'outer x { }");
+    assert_eq!(
+        ast[0],
+        Ast::While(While {
+            is_do_while: false,
+            label: Some(ident!(notvar 1, 7, "outer")),
+            kwd_span: Some(span!(1, 1, "while")),
+            do_kwd_span: None,
+            condition: ident!(1, 13, "x"),
+            content: Block {
+                brace_spans: Some((span!(1, 15, "{"), span!(1, 17, "}"))),
+                content: vec![]
+            },
+            init: None
+        })
+    )
+}
+
+#[test]
+fn break_() {
+    let ast = parse!("break");
+    assert_eq!(
+        ast[0],
+        Ast::LoopControl(LoopControl {
+            is_continue: false,
+            kwd_span: Some(span!(1, 1, "break")),
+            label: None
+        })
+    )
+}
+
+#[test]
+fn labelled_continue() {
+    let ast = parse!("continue'outer");
+    assert_eq!(
+        ast[0],
+        Ast::LoopControl(LoopControl {
+            is_continue: true,
+            kwd_span: Some(span!(1, 1, "continue")),
+            label: Some(ident!(notvar 1, 10, "outer"))
+        })
+    )
+}
+
+#[test]
+fn labelled_return() {
+    let ast = parse!("ret'outer x");
+    assert_eq!(
+        ast[0],
+        Ast::Return(Return {
+            kwd_span: Some(span!(1, 1, "ret")),
+            label: Some(ident!(notvar 1, 5, "outer")),
+            value: ident!(1, 11, "x")
+        })
+    )
+}
+
+#[test]
+fn match_() {
+    let ast = parse!("match x of 1 { } of y { } else { }");
+    assert_eq!(
+        ast[0],
+        Ast::Match(Match {
+            kwd_span: Some(span!(1, 1, "match")),
+            subject: ident!(1, 7, "x"),
+            arms: vec![
+                MatchArm {
+                    kwd_span: Some(span!(1, 9, "of")),
+                    pattern: Some(Pattern::Value(Box::new(Ast::Literal(Literal {
+                        span: Some(span!(1, 12, "1")),
+                        content: Value::I32(1)
+                    })))),
+                    content: Block {
+                        brace_spans: Some((span!(1, 14, "{"), span!(1, 16, "}"))),
+                        content: vec![]
+                    }
+                },
+                MatchArm {
+                    kwd_span: Some(span!(1, 18, "of")),
+                    pattern: Some(Pattern::Binding(ident!(notvar 1, 21, "y"))),
+                    content: Block {
+                        brace_spans: Some((span!(1, 23, "{"), span!(1, 25, "}"))),
+                        content: vec![]
+                    }
+                },
+                MatchArm {
+                    kwd_span: Some(span!(1, 27, "else")),
+                    pattern: None,
+                    content: Block {
+                        brace_spans: Some((span!(1, 32, "{"), span!(1, 34, "}"))),
+                        content: vec![]
+                    }
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn match_patterns() {
+    let ast = parse!("match x of _ { } of y istype T { } of P(a, b: _) { }");
+    let Ast::Match(Match { arms, .. }) = &ast[0] else {
+        panic!("{ast:#?}")
+    };
+    assert_eq!(arms[0].pattern, Some(Pattern::Wildcard(Some(span!(1, 12, "_")))));
+    assert_eq!(
+        arms[1].pattern,
+        Some(Pattern::Type {
+            binding: Some(ident!(notvar 1, 21, "y")),
+            kwd_span: Some(span!(1, 23, "istype")),
+            ty: ident!(1, 30, "T")
+        })
+    );
+    assert_eq!(
+        arms[2].pattern,
+        Some(Pattern::Struct {
+            ty: ident!(1, 39, "P"),
+            paren_spans: Some((span!(1, 40, "("), span!(1, 48, ")"))),
+            fields: vec![
+                (
+                    ident!(notvar 1, 41, "a"),
+                    Pattern::Binding(ident!(notvar 1, 41, "a"))
+                ),
+                (
+                    ident!(notvar 1, 44, "b"),
+                    Pattern::Wildcard(Some(span!(1, 47, "_")))
+                )
+            ]
+        })
+    );
+}
+
+#[test]
+fn unwrap() {
+    for (sy, propagate) in [("!!", false), ("?", true), ("!?", true)] {
+        let s = format!("x{sy}");
+        let ast = parse!(s);
+        assert_eq!(
+            ast[0],
+            Ast::Unwrap(Unwrap {
+                operand: ident!(1, 1, "x"),
+                opr_span: Some(span!(1, 2, sy)),
+                propagate
+            })
+        )
+    }
+}
+
+#[test]
+fn type_args() {
+    let ast = parse!("x: opt[i32] := null");
+    assert_eq!(
+        ast[0],
+        Ast::Declare(Declare {
+            variable: ident!(1, 1, "x"),
+            content: Box::new(Ast::Literal(Literal {
+                span: Some(span!(1, 16, "null")),
+                content: Value::Opt(None)
+            })),
+            flags: vec![],
+            ty: Some(Box::new(Ast::Index(Index {
+                parent: ident!(1, 4, "opt"),
+                bracket_spans: Some((span!(1, 7, "["), span!(1, 11, "]"))),
+                args: vec![*ident!(1, 8, "i32")]
+            }))),
+            eq_span: Some(span!(1, 13, ":=")),
+        })
+    )
+}
+
+#[test]
+fn index() {
+    let ast = parse!("v[0]");
+    assert_eq!(
+        ast[0],
+        Ast::Index(Index {
+            parent: ident!(1, 1, "v"),
+            bracket_spans: Some((span!(1, 2, "["), span!(1, 4, "]"))),
+            args: vec![Ast::Literal(Literal {
+                span: Some(span!(1, 3, "0")),
+                content: Value::I32(0)
+            })]
+        })
+    )
+}
+
+#[test]
+fn call_kwargs() {
+    let ast = parse!("hmap(a: 1)");
+    assert_eq!(
+        ast[0],
+        Ast::Call(Call {
+            called: ident!(1, 1, "hmap"),
+            paren_spans: Some((span!(1, 5, "("), span!(1, 10, ")"))),
+            args: vec![],
+            kwargs: vec![(
+                *ident!(1, 6, "a"),
+                Ast::Literal(Literal {
+                    span: Some(span!(1, 9, "1")),
+                    content: Value::I32(1)
+                })
+            )]
+        })
+    )
+}
+
+#[test]
+fn call_kwargs_expr_key() {
+    let ast = parse!("hmap(1: x)");
+    assert_eq!(
+        ast[0],
+        Ast::Call(Call {
+            called: ident!(1, 1, "hmap"),
+            paren_spans: Some((span!(1, 5, "("), span!(1, 10, ")"))),
+            args: vec![],
+            kwargs: vec![(
+                Ast::Literal(Literal {
+                    span: Some(span!(1, 6, "1")),
+                    content: Value::I32(1)
+                }),
+                *ident!(1, 9, "x")
+            )]
+        })
+    )
+}
+
+#[test]
+fn format_string() {
+    let ast = parse!("f\"a{x}\"");
+    assert_eq!(
+        ast[0],
+        Ast::FormatString(FormatString {
+            parts: vec![
+                Ast::Literal(Literal {
+                    span: Some(span!(1, 3, "a")),
+                    content: Value::Str("a".into())
+                }),
+                *ident!(1, 5, "x")
+            ],
+            span: Some(span!(1, 1, "f\"a{x}\""))
+        })
+    )
+}
+
+#[test]
+fn union_type() {
+    let ast = parse!("x: i32 / str := 1");
+    assert_eq!(
+        ast[0],
+        Ast::Declare(Declare {
+            variable: ident!(1, 1, "x"),
+            content: Box::new(Ast::Literal(Literal {
+                span: Some(span!(1, 17, "1")),
+                content: Value::I32(1)
+            })),
+            flags: vec![],
+            ty: Some(Box::new(Ast::BinaryOpr(BinaryOpr {
+                ty: OprType::Div,
+                opr_span: Some(span!(1, 8, "/")),
+                operand1: ident!(1, 4, "i32"),
+                operand2: ident!(1, 10, "str")
+            }))),
+            eq_span: Some(span!(1, 14, ":=")),
+        })
+    )
+}
+
+#[test]
+fn enum_() {
+    let ast = parse!("enum { inst A; inst B := 3; C: T }");
+    assert_eq!(
+        ast[0],
+        Ast::Enum(Enum {
+            kwd_span: Some(span!(1, 1, "enum")),
+            brace_spans: Some((span!(1, 6, "{"), span!(1, 34, "}"))),
+            variants: vec![
+                EnumVariant {
+                    name: ident!(notvar 1, 13, "A"),
+                    value: None,
+                    ty: None,
+                    fields: vec![]
+                },
+                EnumVariant {
+                    name: ident!(notvar 1, 21, "B"),
+                    value: Some(Ast::Literal(Literal {
+                        span: Some(span!(1, 26, "3")),
+                        content: Value::I32(3)
+                    })),
+                    ty: None,
+                    fields: vec![]
+                },
+                EnumVariant {
+                    name: ident!(notvar 1, 29, "C"),
+                    value: None,
+                    ty: Some(*ident!(1, 32, "T")),
+                    fields: vec![]
+                }
+            ],
+            name: None
+        })
+    )
+}